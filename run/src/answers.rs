@@ -0,0 +1,92 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+use utils::registry::Part;
+
+#[derive(Debug, Default, Deserialize)]
+struct DayAnswers {
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct Answers {
+    answers: HashMap<(u16, u8, Part), String>,
+}
+impl Answers {
+    pub fn path(dir: &Path, year: u16) -> PathBuf {
+        dir.join(format!("{}.toml", year))
+    }
+    pub fn load(dir: &Path, years: &[u16]) -> Result<Self, String> {
+        let mut answers = Self::default();
+        for &year in years {
+            let path = Self::path(dir, year);
+            if !path.exists() {
+                continue;
+            }
+            let content = fs::read_to_string(&path)
+                .map_err(|error| format!("cannot read answers {}: {}", path.display(), error))?;
+            answers
+                .add_year(year, &content)
+                .map_err(|error| format!("invalid answers {}: {}", path.display(), error))?;
+        }
+        Ok(answers)
+    }
+    pub fn get(&self, year: u16, day: u8, part: Part) -> Option<&str> {
+        self.answers.get(&(year, day, part)).map(|it| it.as_str())
+    }
+    pub fn insert(&mut self, year: u16, day: u8, part: Part, answer: String) {
+        self.answers.insert((year, day, part), answer);
+    }
+    fn add_year(&mut self, year: u16, content: &str) -> Result<(), String> {
+        let days: BTreeMap<String, DayAnswers> =
+            toml::from_str(content).map_err(|error| error.to_string())?;
+
+        for (key, answers) in days {
+            let day = key
+                .strip_prefix("day")
+                .and_then(|it| it.parse::<u8>().ok())
+                .ok_or_else(|| format!("invalid day {}, expected dayNN", key))?;
+
+            [(Part::One, answers.part1), (Part::Two, answers.part2)]
+                .into_iter()
+                .filter_map(|(part, answer)| answer.map(|it| (part, it)))
+                .for_each(|(part, answer)| self.insert(year, day, part, answer));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use utils::registry::Part;
+
+    use super::Answers;
+
+    #[test]
+    fn parse_answers() {
+        let mut answers = Answers::default();
+        answers
+            .add_year(
+                2022,
+                "[day01]\npart1 = \"1\"\npart2 = \"2\"\n\n[day25]\npart1 = \"3\"\n",
+            )
+            .unwrap();
+
+        assert_eq!(answers.get(2022, 1, Part::One), Some("1"));
+        assert_eq!(answers.get(2022, 1, Part::Two), Some("2"));
+        assert_eq!(answers.get(2022, 25, Part::One), Some("3"));
+        assert_eq!(answers.get(2022, 25, Part::Two), None);
+        assert_eq!(answers.get(2023, 1, Part::One), None);
+    }
+
+    #[test]
+    fn invalid_day() {
+        let mut answers = Answers::default();
+        assert!(answers.add_year(2022, "[first]\npart1 = \"1\"\n").is_err());
+    }
+}
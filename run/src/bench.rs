@@ -0,0 +1,401 @@
+use std::{
+    fmt::Display,
+    fs,
+    hint::black_box,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+use utils::{
+    input::InputSource,
+    registry::{Day, Part, Registry},
+};
+
+use crate::{runner::isolated, Selection, Status};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Step {
+    Parse,
+    Part1,
+    Part2,
+}
+impl From<Part> for Step {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => Step::Part1,
+            Part::Two => Step::Part2,
+        }
+    }
+}
+impl Display for Step {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Step::Parse => write!(f, "parse"),
+            Step::Part1 => write!(f, "part1"),
+            Step::Part2 => write!(f, "part2"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+    pub stddev_ns: u64,
+}
+impl From<&[Duration]> for Stats {
+    fn from(samples: &[Duration]) -> Self {
+        let mut nanos: Vec<f64> = samples.iter().map(|it| it.as_nanos() as f64).collect();
+        nanos.sort_by(|a, b| a.total_cmp(b));
+
+        let len = nanos.len() as f64;
+        let mean = nanos.iter().sum::<f64>() / len;
+        let variance = nanos.iter().map(|it| (it - mean).powi(2)).sum::<f64>() / len;
+        let median = if nanos.len().is_multiple_of(2) {
+            (nanos[nanos.len() / 2 - 1] + nanos[nanos.len() / 2]) / 2.0
+        } else {
+            nanos[nanos.len() / 2]
+        };
+
+        Self {
+            min_ns: nanos[0] as u64,
+            median_ns: median as u64,
+            mean_ns: mean as u64,
+            stddev_ns: variance.sqrt() as u64,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Measure {
+    pub year: u16,
+    pub day: u8,
+    pub step: Step,
+    pub iterations: usize,
+    #[serde(flatten)]
+    pub stats: Stats,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Failure {
+    pub year: u16,
+    pub day: u8,
+    pub error: String,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Report {
+    pub measures: Vec<Measure>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub failures: Vec<Failure>,
+}
+impl Report {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(path)
+            .map_err(|error| format!("cannot read report {}: {}", path.display(), error))?;
+        serde_json::from_str(&content)
+            .map_err(|error| format!("invalid report {}: {}", path.display(), error))
+    }
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let content = serde_json::to_string_pretty(self).map_err(|error| error.to_string())?;
+        fs::write(path, content)
+            .map_err(|error| format!("cannot write report {}: {}", path.display(), error))
+    }
+    pub fn get(&self, year: u16, day: u8, step: Step) -> Option<&Measure> {
+        self.measures
+            .iter()
+            .find(|it| it.year == year && it.day == day && it.step == step)
+    }
+    pub fn regressions<'a>(&'a self, previous: &Report, threshold: f64) -> Vec<&'a Measure> {
+        self.measures
+            .iter()
+            .filter(|it| {
+                previous
+                    .get(it.year, it.day, it.step)
+                    .is_some_and(|old| change(old, it) > threshold)
+            })
+            .collect()
+    }
+}
+
+fn change(old: &Measure, new: &Measure) -> f64 {
+    let old = old.stats.median_ns.max(1) as f64;
+    let new = new.stats.median_ns as f64;
+    (new - old) / old
+}
+
+fn measure<T>(iterations: usize, mut f: impl FnMut() -> T) -> Stats {
+    let samples: Vec<Duration> = (0..iterations)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+
+    Stats::from(&samples[..])
+}
+
+fn bench_day(
+    day: &Day,
+    input: &str,
+    parts: &[Part],
+    iterations: usize,
+) -> Result<Vec<Measure>, String> {
+    let parsed = day.parse(input)?;
+    let new_measure = |step, stats| Measure {
+        year: day.year,
+        day: day.day,
+        step,
+        iterations,
+        stats,
+    };
+
+    let mut measures = vec![new_measure(
+        Step::Parse,
+        measure(iterations, || day.parse(input)),
+    )];
+    parts.iter().for_each(|&part| {
+        let stats = measure(iterations, || day.solve(part, &parsed));
+        measures.push(new_measure(part.into(), stats));
+    });
+    Ok(measures)
+}
+
+pub fn format_duration(nanos: u64) -> String {
+    match nanos {
+        0..=999 => format!("{}ns", nanos),
+        1_000..=999_999 => format!("{:.2}µs", nanos as f64 / 1e3),
+        1_000_000..=999_999_999 => format!("{:.2}ms", nanos as f64 / 1e6),
+        _ => format!("{:.2}s", nanos as f64 / 1e9),
+    }
+}
+
+fn failure(status: Status) -> String {
+    match status {
+        Status::Timeout(limit) => format!(
+            "timed out after {}",
+            format_duration(limit.as_nanos() as u64)
+        ),
+        status => status.to_string(),
+    }
+}
+
+pub fn bench(
+    registry: &Registry,
+    selection: &Selection,
+    source: &InputSource,
+    iterations: usize,
+    timeout: Option<Duration>,
+    previous: Option<&Report>,
+    threshold: f64,
+) -> Result<Report, String> {
+    let days = selection.days(registry);
+    if days.is_empty() {
+        return Err("no registered day matches the selection".to_string());
+    }
+    if iterations == 0 {
+        return Err("at least one iteration is needed".to_string());
+    }
+
+    println!(
+        "{:<6}{:<5}{:<7}{:>11}{:>11}{:>11}{:>11}{:>9}",
+        "Year", "Day", "Step", "min", "median", "mean", "stddev", "change"
+    );
+
+    let mut report = Report::default();
+    for day in days {
+        let worker = (day.clone(), source.clone(), selection.parts());
+        let measures = isolated(
+            1,
+            timeout,
+            move |emit| {
+                let (day, source, parts) = worker;
+                emit(
+                    source
+                        .read(day.year, day.day)
+                        .and_then(|input| bench_day(&day, &input, &parts, iterations)),
+                )
+            },
+            |_, status| Err(failure(status)),
+        );
+        let measures = match measures.into_iter().next().unwrap_or(Ok(vec![])) {
+            Ok(measures) => measures,
+            Err(error) => {
+                println!(
+                    "{:<6}{:<5}error: {}",
+                    day.year,
+                    format!("{:02}", day.day),
+                    error
+                );
+                report.failures.push(Failure {
+                    year: day.year,
+                    day: day.day,
+                    error,
+                });
+                continue;
+            }
+        };
+        for measure in measures {
+            let change = previous
+                .and_then(|it| it.get(measure.year, measure.day, measure.step))
+                .map(|old| {
+                    let change = change(old, &measure);
+                    let flag = if change > threshold { " !" } else { "" };
+                    format!("{:+.1}%{}", change * 100.0, flag)
+                })
+                .unwrap_or_default();
+
+            println!(
+                "{:<6}{:<5}{:<7}{:>11}{:>11}{:>11}{:>11}{:>9}",
+                measure.year,
+                format!("{:02}", measure.day),
+                measure.step,
+                format_duration(measure.stats.min_ns),
+                format_duration(measure.stats.median_ns),
+                format_duration(measure.stats.mean_ns),
+                format_duration(measure.stats.stddev_ns),
+                change
+            );
+            report.measures.push(measure);
+        }
+    }
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use utils::{input::InputSource, registry::Registry, solution::Solution, ParseError};
+
+    use crate::Selection;
+
+    use super::{bench, format_duration, Measure, Report, Stats, Step};
+
+    fn measure(day: u8, median_ns: u64) -> Measure {
+        Measure {
+            year: 2022,
+            day,
+            step: Step::Part1,
+            iterations: 1,
+            stats: Stats {
+                min_ns: median_ns,
+                median_ns,
+                mean_ns: median_ns,
+                stddev_ns: 0,
+            },
+        }
+    }
+
+    #[test]
+    fn stats_of_samples() {
+        let samples = [4, 1, 3, 2].map(Duration::from_nanos);
+
+        let stats = Stats::from(&samples[..]);
+
+        assert_eq!(stats.min_ns, 1);
+        assert_eq!(stats.median_ns, 2);
+        assert_eq!(stats.mean_ns, 2);
+        assert_eq!(stats.stddev_ns, 1);
+    }
+
+    #[test]
+    fn format_durations() {
+        assert_eq!(format_duration(999), "999ns");
+        assert_eq!(format_duration(1_500), "1.50µs");
+        assert_eq!(format_duration(2_000_000), "2.00ms");
+        assert_eq!(format_duration(3_250_000_000), "3.25s");
+    }
+
+    #[test]
+    fn find_regressions() {
+        let previous = Report {
+            measures: vec![measure(1, 100), measure(2, 100)],
+            ..Report::default()
+        };
+        let actual = Report {
+            measures: vec![measure(1, 105), measure(2, 150), measure(3, 500)],
+            ..Report::default()
+        };
+
+        let regressions = actual.regressions(&previous, 0.1);
+
+        assert_eq!(regressions, vec![&measure(2, 150)]);
+    }
+
+    #[test]
+    fn report_roundtrip() {
+        let report = Report {
+            measures: vec![measure(1, 100)],
+            ..Report::default()
+        };
+
+        let json = serde_json::to_string(&report).unwrap();
+
+        assert!(json.contains("\"step\":\"part1\""));
+        assert!(!json.contains("failures"));
+        assert_eq!(serde_json::from_str::<Report>(&json).unwrap(), report);
+    }
+
+    #[test]
+    fn continue_after_a_failing_day() {
+        struct Strict;
+        impl Solution for Strict {
+            type Input = u64;
+            type Part1 = u64;
+            type Part2 = u64;
+
+            fn parse(input: &str) -> Result<Self::Input, ParseError> {
+                utils::parse::field(input, 1, input.trim())
+            }
+            fn part1(input: &Self::Input) -> Self::Part1 {
+                assert_ne!(*input, 0, "zero");
+                *input
+            }
+            fn part2(input: &Self::Input) -> Self::Part2 {
+                std::thread::sleep(Duration::from_millis(*input));
+                *input
+            }
+        }
+
+        let mut registry = Registry::new();
+        (1..=5).for_each(|day| registry.add::<Strict>(2022, day));
+        let inputs = std::env::temp_dir().join(format!("aoc-bench-{}", std::process::id()));
+        std::fs::create_dir_all(inputs.join("2022")).unwrap();
+        [(1, "x"), (3, "3"), (4, "0"), (5, "5000")]
+            .iter()
+            .for_each(|(day, input)| {
+                std::fs::write(InputSource::path(&inputs, 2022, *day), input).unwrap()
+            });
+
+        let report = bench(
+            &registry,
+            &Selection::all(),
+            &InputSource::Dir(inputs.clone()),
+            1,
+            Some(Duration::from_millis(200)),
+            None,
+            0.1,
+        )
+        .unwrap();
+        std::fs::remove_dir_all(inputs).unwrap();
+
+        assert_eq!(
+            report.failures.iter().map(|it| it.day).collect::<Vec<_>>(),
+            [1, 2, 4, 5]
+        );
+        assert!(report.failures[0].error.contains("invalid digit"));
+        assert!(report.failures[2].error.starts_with("panicked: "));
+        assert_eq!(report.failures[3].error, "timed out after 200.00ms");
+        assert_eq!(report.measures.len(), 3);
+        assert!(report
+            .measures
+            .iter()
+            .all(|it| it.day == 3 && it.iterations == 1));
+    }
+}
@@ -0,0 +1,324 @@
+use std::{
+    fmt::Display,
+    fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::Duration,
+};
+
+use serde::Deserialize;
+
+use crate::Format;
+
+pub const CONFIG: &str = ".aoc.toml";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Source {
+    Default,
+    File(PathBuf),
+    Env(String),
+}
+impl Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::Default => write!(f, "default"),
+            Source::File(path) => write!(f, "{}", path.display()),
+            Source::Env(var) => write!(f, "env {}", var),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Setting<T> {
+    pub value: T,
+    pub source: Source,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct File {
+    inputs: Option<PathBuf>,
+    answers: Option<PathBuf>,
+    session: Option<PathBuf>,
+    year: Option<u16>,
+    format: Option<String>,
+    timeout: Option<f64>,
+    iterations: Option<usize>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Config {
+    pub inputs: Setting<PathBuf>,
+    pub answers: Setting<PathBuf>,
+    pub session: Setting<PathBuf>,
+    pub token: Setting<Option<String>>,
+    pub year: Setting<Option<u16>>,
+    pub format: Setting<Format>,
+    pub timeout: Setting<Option<Duration>>,
+    pub iterations: Setting<usize>,
+}
+impl Config {
+    /// Loads the given configuration file, or the optional [CONFIG] in the
+    /// working directory when no file is given.
+    pub fn load(path: Option<&Path>) -> Result<Self, String> {
+        let env = |var: &str| std::env::var(var).ok();
+        match path {
+            Some(path) => Self::resolve(path, true, env),
+            None => Self::resolve(Path::new(CONFIG), false, env),
+        }
+    }
+    fn resolve(
+        path: &Path,
+        required: bool,
+        env: impl Fn(&str) -> Option<String>,
+    ) -> Result<Self, String> {
+        let file: File = match fs::read_to_string(path) {
+            Ok(content) => toml::from_str(&content)
+                .map_err(|error| format!("invalid config {}: {}", path.display(), error))?,
+            Err(error) if !required && error.kind() == ErrorKind::NotFound => File::default(),
+            Err(error) => return Err(format!("cannot read {}: {}", path.display(), error)),
+        };
+        // Paths in the file are relative to the file, not to the working directory.
+        let base = path.parent().unwrap_or(Path::new(""));
+        let relative = |it: Option<PathBuf>| it.map(|it| base.join(it));
+        let invalid =
+            |key: &str, error: String| format!("invalid {} in {}: {}", key, path.display(), error);
+        let format = file
+            .format
+            .map(|it| it.parse())
+            .transpose()
+            .map_err(|error| invalid("format", error))?;
+        let timeout = file
+            .timeout
+            .map(|it| Duration::try_from_secs_f64(it).map_err(|error| error.to_string()))
+            .transpose()
+            .map_err(|error| invalid("timeout", error))?;
+
+        let setting = Resolver { path, env: &env };
+        Ok(Self {
+            inputs: setting.get(
+                PathBuf::from("inputs"),
+                relative(file.inputs),
+                "AOC_INPUTS",
+                path_buf,
+            )?,
+            answers: setting.get(
+                PathBuf::from("answers"),
+                relative(file.answers),
+                "AOC_ANSWERS",
+                path_buf,
+            )?,
+            session: setting.get(
+                PathBuf::from(".aoc-session"),
+                relative(file.session),
+                "AOC_SESSION_FILE",
+                path_buf,
+            )?,
+            token: setting.get(None, None, "AOC_SESSION", |it| Ok(Some(it.to_string())))?,
+            year: setting.get(None, file.year.map(Some), "AOC_YEAR", |it| {
+                parse(it).map(Some)
+            })?,
+            format: setting.get(Format::default(), format, "AOC_FORMAT", parse)?,
+            timeout: setting.get(None, timeout.map(Some), "AOC_TIMEOUT", |it| {
+                seconds(it).map(Some)
+            })?,
+            iterations: setting.get(10, file.iterations, "AOC_BENCH_ITERATIONS", parse)?,
+        })
+    }
+    pub fn show(&self) -> String {
+        [
+            row("Key", "Value", &"Source"),
+            row("inputs", self.inputs.value.display(), &self.inputs.source),
+            row(
+                "answers",
+                self.answers.value.display(),
+                &self.answers.source,
+            ),
+            row(
+                "session",
+                self.session.value.display(),
+                &self.session.source,
+            ),
+            row(
+                "token",
+                self.token.value.as_ref().map_or("none", |_| "set"),
+                &self.token.source,
+            ),
+            row("year", optional(self.year.value), &self.year.source),
+            row("format", self.format.value, &self.format.source),
+            row(
+                "timeout",
+                optional(
+                    self.timeout
+                        .value
+                        .map(|it| format!("{}s", it.as_secs_f64())),
+                ),
+                &self.timeout.source,
+            ),
+            row("iterations", self.iterations.value, &self.iterations.source),
+        ]
+        .concat()
+    }
+}
+
+fn row(key: &str, value: impl Display, source: &dyn Display) -> String {
+    format!("{:<12}{:<16}{}\n", key, value.to_string(), source)
+}
+
+fn optional(value: Option<impl Display>) -> String {
+    value.map_or("none".to_string(), |it| it.to_string())
+}
+
+fn path_buf(value: &str) -> Result<PathBuf, String> {
+    Ok(PathBuf::from(value))
+}
+
+fn parse<T: FromStr>(value: &str) -> Result<T, String>
+where
+    T::Err: Display,
+{
+    value
+        .trim()
+        .parse()
+        .map_err(|error: T::Err| error.to_string())
+}
+
+pub fn seconds(value: &str) -> Result<Duration, String> {
+    value
+        .trim()
+        .parse()
+        .ok()
+        .and_then(|it| Duration::try_from_secs_f64(it).ok())
+        .ok_or_else(|| format!("invalid number of seconds {}", value))
+}
+
+struct Resolver<'a, E: Fn(&str) -> Option<String>> {
+    path: &'a Path,
+    env: &'a E,
+}
+impl<E: Fn(&str) -> Option<String>> Resolver<'_, E> {
+    fn get<T>(
+        &self,
+        default: T,
+        file: Option<T>,
+        var: &str,
+        parse: impl Fn(&str) -> Result<T, String>,
+    ) -> Result<Setting<T>, String> {
+        if let Some(value) = (self.env)(var) {
+            return parse(&value)
+                .map(|value| Setting {
+                    value,
+                    source: Source::Env(var.to_string()),
+                })
+                .map_err(|error| format!("invalid {}: {}", var, error));
+        }
+        Ok(match file {
+            Some(value) => Setting {
+                value,
+                source: Source::File(self.path.to_path_buf()),
+            },
+            None => Setting {
+                value: default,
+                source: Source::Default,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        fs,
+        path::{Path, PathBuf},
+        time::Duration,
+    };
+
+    use crate::Format;
+
+    use super::{Config, Source};
+
+    fn config(content: &str, env: &[(&str, &str)]) -> Result<Config, String> {
+        let dir = std::env::temp_dir().join(format!("aoc-config-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(format!("{}.toml", utils::input::hash(content)));
+        fs::write(&path, content).unwrap();
+        let env: HashMap<String, String> = env
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+
+        let config = Config::resolve(&path, true, |var| env.get(var).cloned());
+        fs::remove_file(path).unwrap();
+        config
+    }
+
+    #[test]
+    fn defaults_without_file() {
+        let config = Config::resolve(Path::new("missing.toml"), false, |_| None).unwrap();
+
+        assert_eq!(config.inputs.value, PathBuf::from("inputs"));
+        assert_eq!(config.inputs.source, Source::Default);
+        assert_eq!(config.year.value, None);
+        assert_eq!(config.format.value, Format::Table);
+        assert_eq!(config.iterations.value, 10);
+        assert_eq!(config.token.value, None);
+    }
+
+    #[test]
+    fn explicit_file_must_exist() {
+        let error = Config::resolve(Path::new("missing.toml"), true, |_| None).unwrap_err();
+        assert!(error.starts_with("cannot read missing.toml: "));
+
+        let dir = std::env::temp_dir();
+        let error = Config::resolve(&dir, false, |_| None).unwrap_err();
+        assert!(error.starts_with(&format!("cannot read {}: ", dir.display())));
+    }
+
+    #[test]
+    fn file_and_env_overrides() {
+        let config = config(
+            "inputs = \"data\"\nsession = \"/etc/aoc-session\"\nyear = 2022\nformat = \"json\"\ntimeout = 2.5\n",
+            &[
+                ("AOC_YEAR", "2023"),
+                ("AOC_BENCH_ITERATIONS", "3"),
+                ("AOC_SESSION", "secret"),
+            ],
+        )
+        .unwrap();
+
+        let dir = std::env::temp_dir().join(format!("aoc-config-{}", std::process::id()));
+        assert_eq!(config.inputs.value, dir.join("data"));
+        assert_eq!(config.session.value, PathBuf::from("/etc/aoc-session"));
+        assert!(matches!(config.inputs.source, Source::File(_)));
+        assert_eq!(config.year.value, Some(2023));
+        assert_eq!(config.year.source, Source::Env("AOC_YEAR".to_string()));
+        assert_eq!(config.format.value, Format::Json);
+        assert_eq!(config.timeout.value, Some(Duration::from_millis(2500)));
+        assert_eq!(config.iterations.value, 3);
+        assert_eq!(config.answers.source, Source::Default);
+
+        let show = config.show();
+        assert!(show.starts_with("Key         Value           Source\n"));
+        assert!(show.contains("year        2023            env AOC_YEAR\n"));
+        assert!(show.contains("timeout     2.5s            "));
+        assert!(show.contains("answers     answers         default\n"));
+        assert!(show.contains("token       set             env AOC_SESSION\n"));
+        assert!(!show.contains("secret"));
+    }
+
+    #[test]
+    fn invalid_values() {
+        assert!(config("colour = 1\n", &[])
+            .unwrap_err()
+            .contains("unknown field"));
+        assert!(config("format = \"xml\"\n", &[])
+            .unwrap_err()
+            .contains("format"));
+        assert_eq!(
+            config("", &[("AOC_TIMEOUT", "-1")]).unwrap_err(),
+            "invalid AOC_TIMEOUT: invalid number of seconds -1"
+        );
+    }
+}
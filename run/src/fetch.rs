@@ -0,0 +1,264 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use utils::input::InputSource;
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "github.com/danielefongo/aoc";
+const LAST_FETCH: &str = ".last-fetch";
+
+pub trait Http {
+    fn get(&self, url: &str, session: &str) -> Result<String, String>;
+    fn post(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<String, String>;
+}
+
+pub struct UreqHttp;
+impl Http for UreqHttp {
+    fn get(&self, url: &str, session: &str) -> Result<String, String> {
+        ureq::get(url)
+            .header("Cookie", &format!("session={}", session))
+            .header("User-Agent", USER_AGENT)
+            .call()
+            .and_then(|mut response| response.body_mut().read_to_string())
+            .map_err(|error| format!("cannot fetch {}: {}", url, error))
+    }
+    fn post(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<String, String> {
+        ureq::post(url)
+            .header("Cookie", &format!("session={}", session))
+            .header("User-Agent", USER_AGENT)
+            .send_form(form.iter().copied())
+            .and_then(|mut response| response.body_mut().read_to_string())
+            .map_err(|error| format!("cannot post to {}: {}", url, error))
+    }
+}
+
+pub struct Fetcher<H: Http> {
+    pub http: H,
+    pub base_url: String,
+    pub session: String,
+    pub cache: PathBuf,
+    pub rate_limit: Duration,
+}
+impl<H: Http> Fetcher<H> {
+    pub fn url(&self, year: u16, day: u8) -> String {
+        format!(
+            "{}/{}/day/{}/input",
+            self.base_url.trim_end_matches('/'),
+            year,
+            day
+        )
+    }
+    pub fn fetch(&self, year: u16, day: u8) -> Result<PathBuf, String> {
+        let path = InputSource::path(&self.cache, year, day);
+        if path.exists() {
+            return Ok(path);
+        }
+
+        self.wait_rate_limit();
+        let input = self.http.get(&self.url(year, day), &self.session);
+        self.mark_fetch()?;
+        let input = input?;
+
+        let dir = path.parent().unwrap_or(&self.cache);
+        fs::create_dir_all(dir)
+            .map_err(|error| format!("cannot create {}: {}", dir.display(), error))?;
+        fs::write(&path, input)
+            .map_err(|error| format!("cannot write input {}: {}", path.display(), error))?;
+        Ok(path)
+    }
+    fn wait_rate_limit(&self) {
+        let elapsed = last_fetch(&self.cache).and_then(|it| now().checked_sub(it));
+        if let Some(remaining) = elapsed.and_then(|it| self.rate_limit.checked_sub(it)) {
+            thread::sleep(remaining);
+        }
+    }
+    fn mark_fetch(&self) -> Result<(), String> {
+        let path = self.cache.join(LAST_FETCH);
+        fs::create_dir_all(&self.cache)
+            .and_then(|_| fs::write(&path, now().as_nanos().to_string()))
+            .map_err(|error| format!("cannot write {}: {}", path.display(), error))
+    }
+}
+
+/// Returns the configured session token, or reads it from `file` when none is set.
+/// Blank tokens count as missing.
+pub fn session(token: Option<&str>, file: &Path) -> Result<String, String> {
+    token
+        .map(str::to_string)
+        .filter(|it| !it.trim().is_empty())
+        .or_else(|| fs::read_to_string(file).ok())
+        .map(|it| it.trim().to_string())
+        .filter(|it| !it.is_empty())
+        .ok_or_else(|| {
+            format!(
+                "missing session token, set AOC_SESSION or write it to {}",
+                file.display()
+            )
+        })
+}
+
+fn now() -> Duration {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+}
+
+fn last_fetch(cache: &Path) -> Option<Duration> {
+    fs::read_to_string(cache.join(LAST_FETCH))
+        .ok()
+        .and_then(|it| it.trim().parse().ok())
+        .map(Duration::from_nanos)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::RefCell,
+        fs,
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        path::{Path, PathBuf},
+        thread,
+        time::{Duration, Instant},
+    };
+
+    use super::{session, Fetcher, Http, UreqHttp};
+
+    struct Stub {
+        requests: RefCell<Vec<(String, String)>>,
+    }
+    impl Http for &Stub {
+        fn get(&self, url: &str, session: &str) -> Result<String, String> {
+            self.requests
+                .borrow_mut()
+                .push((url.to_string(), session.to_string()));
+            Ok("1\n2\n".to_string())
+        }
+        fn post(
+            &self,
+            _url: &str,
+            _session: &str,
+            _form: &[(&str, &str)],
+        ) -> Result<String, String> {
+            Err("unexpected post".to_string())
+        }
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn fetcher<H: Http>(http: H, base_url: &str, cache: &Path) -> Fetcher<H> {
+        Fetcher {
+            http,
+            base_url: base_url.to_string(),
+            session: "secret".to_string(),
+            cache: cache.to_path_buf(),
+            rate_limit: Duration::ZERO,
+        }
+    }
+
+    #[test]
+    fn fetch_and_cache_input() {
+        let cache = temp_dir("cache");
+        let stub = Stub {
+            requests: RefCell::new(vec![]),
+        };
+        let fetcher = fetcher(&stub, "http://stub/", &cache);
+
+        let path = fetcher.fetch(2022, 5).unwrap();
+        fetcher.fetch(2022, 5).unwrap();
+
+        assert_eq!(path, cache.join("2022/day05.txt"));
+        assert_eq!(fs::read_to_string(path).unwrap(), "1\n2\n");
+        assert_eq!(
+            stub.requests.borrow().as_slice(),
+            [(
+                "http://stub/2022/day/5/input".to_string(),
+                "secret".to_string()
+            )]
+        );
+        fs::remove_dir_all(cache).unwrap();
+    }
+
+    #[test]
+    fn respect_rate_limit() {
+        let cache = temp_dir("rate");
+        let stub = Stub {
+            requests: RefCell::new(vec![]),
+        };
+        let mut fetcher = fetcher(&stub, "http://stub", &cache);
+        fetcher.rate_limit = Duration::from_millis(200);
+
+        let start = Instant::now();
+        fetcher.fetch(2022, 1).unwrap();
+        fetcher.fetch(2022, 2).unwrap();
+
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        assert_eq!(stub.requests.borrow().len(), 2);
+        fs::remove_dir_all(cache).unwrap();
+    }
+
+    #[test]
+    fn fetch_from_local_server() {
+        let cache = temp_dir("server");
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let headers: Vec<String> = BufReader::new(&stream)
+                .lines()
+                .map(|it| it.unwrap())
+                .take_while(|it| !it.is_empty())
+                .collect();
+            let body = "input\n";
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            )
+            .unwrap();
+            headers
+        });
+
+        let path = fetcher(UreqHttp, &base_url, &cache)
+            .fetch(2023, 12)
+            .unwrap();
+        let headers = server.join().unwrap();
+
+        assert_eq!(fs::read_to_string(path).unwrap(), "input\n");
+        assert_eq!(headers[0], "GET /2023/day/12/input HTTP/1.1");
+        assert!(headers
+            .iter()
+            .any(|it| it.eq_ignore_ascii_case("cookie: session=secret")));
+        fs::remove_dir_all(cache).unwrap();
+    }
+
+    #[test]
+    fn reject_blank_session_tokens() {
+        let dir = temp_dir("session");
+        let file = dir.join(".aoc-session");
+        let missing = format!(
+            "missing session token, set AOC_SESSION or write it to {}",
+            file.display()
+        );
+
+        assert_eq!(session(Some(" secret\n"), &file), Ok("secret".to_string()));
+        assert_eq!(session(Some(" \n"), &file), Err(missing.clone()));
+        assert_eq!(session(None, &file), Err(missing.clone()));
+
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(&file, "\n").unwrap();
+        assert_eq!(session(Some(""), &file), Err(missing));
+        fs::write(&file, "stored\n").unwrap();
+        assert_eq!(session(Some(""), &file), Ok("stored".to_string()));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
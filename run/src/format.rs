@@ -0,0 +1,280 @@
+use std::{fmt::Display, str::FromStr};
+
+use serde::Serialize;
+use utils::{answer::Answer, registry::Part};
+
+use crate::{Row, Status, Summary};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Table,
+    Json,
+    Junit,
+}
+impl Format {
+    pub fn render(&self, summary: &Summary) -> String {
+        match self {
+            Format::Table => summary.to_string(),
+            Format::Json => json(summary),
+            Format::Junit => junit(summary),
+        }
+    }
+}
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "table" => Ok(Format::Table),
+            "json" => Ok(Format::Json),
+            "junit" => Ok(Format::Junit),
+            other => Err(format!(
+                "invalid format {}, expected table, json or junit",
+                other
+            )),
+        }
+    }
+}
+impl Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Format::Table => write!(f, "table"),
+            Format::Json => write!(f, "json"),
+            Format::Junit => write!(f, "junit"),
+        }
+    }
+}
+
+fn status(row: &Row) -> &'static str {
+    match row.status {
+        Status::Solved if matches!(row.answer, Some(Answer::Unsolved)) => "unsolved",
+        Status::Solved if row.is_wrong() => "wrong",
+        Status::Solved => "ok",
+        Status::Invalid(_) => "invalid",
+        Status::NoInput(_) => "no_input",
+        Status::Timeout(_) => "timeout",
+        Status::Panicked(_) => "panicked",
+    }
+}
+
+#[derive(Serialize)]
+struct JsonReport<'a> {
+    results: Vec<JsonResult<'a>>,
+}
+
+#[derive(Serialize)]
+struct JsonResult<'a> {
+    year: u16,
+    day: u8,
+    part: u8,
+    status: &'static str,
+    answer: Option<String>,
+    expected: Option<&'a str>,
+    time_ns: Option<u64>,
+    input_hash: Option<&'a str>,
+    error: Option<String>,
+}
+
+pub fn json(summary: &Summary) -> String {
+    let results = summary
+        .rows
+        .iter()
+        .map(|row| JsonResult {
+            year: row.year,
+            day: row.day,
+            part: match row.part {
+                Part::One => 1,
+                Part::Two => 2,
+            },
+            status: status(row),
+            answer: row.answer_text(),
+            expected: row.expected.as_deref().map(str::trim),
+            time_ns: row.time.map(|it| it.as_nanos() as u64),
+            input_hash: row.input_hash.as_deref(),
+            error: row.error(),
+        })
+        .collect();
+
+    serde_json::to_string_pretty(&JsonReport { results }).unwrap() + "\n"
+}
+
+fn escape(text: &str) -> String {
+    text.chars()
+        .map(|it| match it {
+            '&' => "&amp;".to_string(),
+            '<' => "&lt;".to_string(),
+            '>' => "&gt;".to_string(),
+            '"' => "&quot;".to_string(),
+            '\'' => "&apos;".to_string(),
+            other => other.to_string(),
+        })
+        .collect()
+}
+
+fn seconds(rows: &[&Row]) -> String {
+    let nanos: u128 = rows
+        .iter()
+        .filter_map(|it| it.time)
+        .map(|it| it.as_nanos())
+        .sum();
+    format!("{:.6}", nanos as f64 / 1e9)
+}
+
+fn testcase(row: &Row) -> String {
+    let opening = format!(
+        "    <testcase classname=\"year_{}.day{:02}\" name=\"part{}\" time=\"{}\"",
+        row.year,
+        row.day,
+        row.part,
+        seconds(&[row])
+    );
+    let message = row.error().map(|it| escape(&it)).unwrap_or_default();
+    let body = match status(row) {
+        "ok" => return format!("{}/>\n", opening),
+        "wrong" => format!("<failure type=\"wrong\" message=\"{}\"/>", message),
+        "unsolved" => "<skipped/>".to_string(),
+        "no_input" => format!("<skipped message=\"{}\"/>", message),
+        kind => format!("<error type=\"{}\" message=\"{}\"/>", kind, message),
+    };
+    format!("{}>\n      {}\n    </testcase>\n", opening, body)
+}
+
+fn counts(rows: &[&Row]) -> String {
+    let count = |kinds: &[&str]| rows.iter().filter(|it| kinds.contains(&status(it))).count();
+    format!(
+        "tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\" time=\"{}\"",
+        rows.len(),
+        count(&["wrong"]),
+        count(&["invalid", "timeout", "panicked"]),
+        count(&["unsolved", "no_input"]),
+        seconds(rows)
+    )
+}
+
+pub fn junit(summary: &Summary) -> String {
+    let rows: Vec<&Row> = summary.rows.iter().collect();
+    let mut years: Vec<u16> = rows.iter().map(|it| it.year).collect();
+    years.dedup();
+
+    let mut xml = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n".to_string();
+    xml += &format!("<testsuites name=\"aoc\" {}>\n", counts(&rows));
+    for year in years {
+        let suite: Vec<&Row> = rows.iter().copied().filter(|it| it.year == year).collect();
+        xml += &format!("  <testsuite name=\"year_{}\" {}>\n", year, counts(&suite));
+        suite.iter().for_each(|row| xml += &testcase(row));
+        xml += "  </testsuite>\n";
+    }
+    xml + "</testsuites>\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use utils::{answer::Answer, registry::Part};
+
+    use crate::{Row, Status, Summary};
+
+    use super::{json, junit, Format};
+
+    fn summary() -> Summary {
+        let row = |day, part, answer: Option<i128>, expected: Option<&str>, status| Row {
+            year: 2022,
+            day,
+            part,
+            answer: answer.map(Answer::Int),
+            time: answer.map(|_| Duration::from_micros(5)),
+            status,
+            expected: expected.map(str::to_string),
+            input_hash: Some("cbf29ce484222325".to_string()),
+        };
+        Summary {
+            rows: vec![
+                row(1, Part::One, Some(24), Some("24\n"), Status::Solved),
+                row(1, Part::Two, Some(7), Some("8"), Status::Solved),
+                row(
+                    2,
+                    Part::One,
+                    None,
+                    None,
+                    Status::Timeout(Duration::from_secs(1)),
+                ),
+                row(
+                    3,
+                    Part::One,
+                    None,
+                    None,
+                    Status::Panicked("a < b".to_string()),
+                ),
+            ],
+        }
+    }
+
+    #[test]
+    fn parse_formats() {
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert_eq!(Format::Junit.to_string(), "junit");
+        assert!("xml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn json_report() {
+        let report: serde_json::Value = serde_json::from_str(&json(&summary())).unwrap();
+        let results = report["results"].as_array().unwrap();
+
+        assert_eq!(results.len(), 4);
+        assert_eq!(results[1]["part"], 2);
+        assert_eq!(results[0]["answer"], "24");
+        assert_eq!(results[0]["expected"], "24");
+        assert_eq!(results[0]["status"], "ok");
+        assert_eq!(results[0]["time_ns"], 5000);
+        assert_eq!(results[0]["input_hash"], "cbf29ce484222325");
+        assert_eq!(results[1]["status"], "wrong");
+        assert_eq!(results[2]["status"], "timeout");
+        assert_eq!(results[2]["answer"], serde_json::Value::Null);
+        assert_eq!(results[3]["error"], "2022 day 03 part 1 panicked: a < b");
+    }
+
+    #[test]
+    fn junit_report() {
+        let xml = junit(&summary());
+
+        assert!(xml.contains(
+            "<testsuites name=\"aoc\" tests=\"4\" failures=\"1\" errors=\"2\" skipped=\"0\" time=\"0.000010\">"
+        ));
+        assert!(xml.contains(
+            "<testcase classname=\"year_2022.day01\" name=\"part1\" time=\"0.000005\"/>"
+        ));
+        assert!(xml.contains(
+            "<failure type=\"wrong\" message=\"2022 day 01 part 2 answered 7, expected 8\"/>"
+        ));
+        assert!(xml.contains("<error type=\"timeout\""));
+        assert!(xml.contains("panicked: a &lt; b"));
+        assert!(xml.ends_with("  </testsuite>\n</testsuites>\n"));
+    }
+
+    #[test]
+    fn skip_unsolved_parts() {
+        let unsolved = Row {
+            year: 2022,
+            day: 25,
+            part: Part::Two,
+            answer: Some(Answer::Unsolved),
+            time: Some(Duration::from_micros(1)),
+            status: Status::Solved,
+            expected: None,
+            input_hash: None,
+        };
+        let summary = Summary {
+            rows: vec![unsolved],
+        };
+
+        let report: serde_json::Value = serde_json::from_str(&json(&summary)).unwrap();
+        assert_eq!(report["results"][0]["status"], "unsolved");
+
+        let xml = junit(&summary);
+        assert!(xml.contains("tests=\"1\" failures=\"0\" errors=\"0\" skipped=\"1\""));
+        assert!(xml.contains("      <skipped/>\n"));
+    }
+}
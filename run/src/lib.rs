@@ -1,14 +1,27 @@
-pub fn add(left: usize, right: usize) -> usize {
-    left + right
-}
+mod answers;
+mod bench;
+mod config;
+mod fetch;
+mod format;
+mod pool;
+mod runner;
+mod scaffold;
+mod selection;
+mod submit;
+mod summary;
+mod verify;
+mod watch;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
-    }
-}
+pub use answers::Answers;
+pub use bench::{bench, Report};
+pub use config::{seconds, Config, Setting, Source, CONFIG};
+pub use fetch::{session, Fetcher, Http, UreqHttp, BASE_URL};
+pub use format::Format;
+pub use pool::default_jobs;
+pub use runner::{run, RunOptions};
+pub use scaffold::scaffold;
+pub use selection::{DayRange, Selection};
+pub use submit::{History, Submitter, Verdict};
+pub use summary::{Row, Status, Summary};
+pub use verify::verify;
+pub use watch::Watch;
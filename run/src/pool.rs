@@ -0,0 +1,60 @@
+use std::{
+    num::NonZeroUsize,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+
+pub fn default_jobs() -> usize {
+    thread::available_parallelism().map_or(1, NonZeroUsize::get)
+}
+
+pub fn map<T: Sync, R: Send>(items: &[T], jobs: usize, f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    let next = AtomicUsize::new(0);
+    let workers = jobs.clamp(1, items.len().max(1));
+
+    let mut results: Vec<(usize, R)> = thread::scope(|scope| {
+        let handles: Vec<_> = (0..workers)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut results = vec![];
+                    loop {
+                        let idx = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(item) = items.get(idx) else {
+                            return results;
+                        };
+                        results.push((idx, f(item)));
+                    }
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect()
+    });
+
+    results.sort_by_key(|(idx, _)| *idx);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{thread, time::Duration};
+
+    use super::map;
+
+    #[test]
+    fn keep_input_order() {
+        let items: Vec<u64> = (0..20).collect();
+
+        let results = map(&items, 4, |&it| {
+            thread::sleep(Duration::from_millis(20 - it));
+            it * 2
+        });
+
+        assert_eq!(results, (0..20).map(|it| it * 2).collect::<Vec<_>>());
+        assert_eq!(map(&items, 0, |&it| it), items);
+        assert!(map(&[] as &[u64], 4, |&it| it).is_empty());
+    }
+}
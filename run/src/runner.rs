@@ -0,0 +1,313 @@
+use std::{
+    any::Any,
+    fs,
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+    sync::{
+        mpsc::{self, RecvTimeoutError},
+        Once,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use utils::{
+    input::{self, InputSource},
+    registry::{Day, Part, Registry},
+};
+
+use crate::{pool, Answers, Format, Row, Selection, Status, Summary};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RunOptions {
+    pub jobs: usize,
+    pub timeout: Option<Duration>,
+    pub format: Format,
+    pub output: Option<PathBuf>,
+}
+impl Default for RunOptions {
+    fn default() -> Self {
+        Self {
+            jobs: pool::default_jobs(),
+            timeout: None,
+            format: Format::default(),
+            output: None,
+        }
+    }
+}
+
+pub fn run(
+    registry: &Registry,
+    selection: &Selection,
+    source: &InputSource,
+    answers: &Answers,
+    options: &RunOptions,
+) -> Result<(), String> {
+    let days = selection.days(registry);
+    if days.is_empty() {
+        return Err("no registered day matches the selection".to_string());
+    }
+    if source.is_single() && days.len() > 1 {
+        return Err("a single input can only be used with a single day".to_string());
+    }
+
+    let mut summary = solve(&days, source, &selection.parts(), options);
+    summary.rows.iter_mut().for_each(|row| {
+        row.expected = answers.get(row.year, row.day, row.part).map(str::to_string)
+    });
+
+    let report = options.format.render(&summary);
+    match &options.output {
+        Some(path) => fs::write(path, report)
+            .map_err(|error| format!("cannot write report {}: {}", path.display(), error))?,
+        None => print!("{}", report),
+    }
+
+    match summary.failures() {
+        None => Ok(()),
+        Some(failures) => Err(failures),
+    }
+}
+
+pub fn solve(days: &[&Day], source: &InputSource, parts: &[Part], options: &RunOptions) -> Summary {
+    let rows = pool::map(days, options.jobs, |day| {
+        isolate(day, source, parts, options.timeout)
+    });
+    Summary {
+        rows: rows.into_iter().flatten().collect(),
+    }
+}
+
+const SOLVER: &str = "aoc-solver";
+
+/// Keeps the default panic hook for every thread but the solvers, whose
+/// panics are caught and reported in the summary instead.
+fn quiet_solver_panics() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let default = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if thread::current().name() != Some(SOLVER) {
+                default(info)
+            }
+        }));
+    });
+}
+
+fn isolate(day: &Day, source: &InputSource, parts: &[Part], timeout: Option<Duration>) -> Vec<Row> {
+    let worker = (day.clone(), source.clone(), parts.to_vec());
+    isolated(
+        parts.len(),
+        timeout,
+        move |emit| {
+            let (day, source, parts) = worker;
+            solve_day(&day, &source, &parts, emit)
+        },
+        |index, status| row(day, parts[index], status),
+    )
+}
+
+/// Runs `work` on its own thread, collecting the `count` items it emits, and
+/// stops waiting for it after `timeout`. The items it could not emit because
+/// it panicked or timed out are replaced by `failed`.
+///
+/// Threads cannot be killed, so work that times out keeps running detached
+/// until it finishes or the process exits. This leaks its CPU time and memory
+/// for the rest of the run, which is fine for a command line tool that exits
+/// right after printing the summary.
+pub(crate) fn isolated<T: Send + 'static>(
+    count: usize,
+    timeout: Option<Duration>,
+    work: impl FnOnce(&mut dyn FnMut(T)) + Send + 'static,
+    failed: impl Fn(usize, Status) -> T,
+) -> Vec<T> {
+    quiet_solver_panics();
+    let (sender, receiver) = mpsc::channel();
+    let deadline = timeout.map(|it| Instant::now() + it);
+
+    let spawned = thread::Builder::new()
+        .name(SOLVER.to_string())
+        .spawn(move || {
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                work(&mut |item| {
+                    let _ = sender.send(Ok(item));
+                })
+            }));
+            if let Err(payload) = result {
+                let _ = sender.send(Err(panic_message(payload.as_ref())));
+            }
+        });
+    if let Err(error) = spawned {
+        let status = Status::Panicked(format!("cannot start the solver: {}", error));
+        return (0..count)
+            .map(|index| failed(index, status.clone()))
+            .collect();
+    }
+
+    let mut items = vec![];
+    let fill = |items: &mut Vec<T>, status: Status| {
+        (items.len()..count).for_each(|index| items.push(failed(index, status.clone())));
+    };
+    while items.len() < count {
+        let received = match deadline {
+            Some(deadline) => {
+                receiver.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+            }
+            None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+        match received {
+            Ok(Ok(item)) => items.push(item),
+            Ok(Err(message)) => fill(&mut items, Status::Panicked(message)),
+            Err(RecvTimeoutError::Timeout) => {
+                fill(&mut items, Status::Timeout(timeout.unwrap_or_default()))
+            }
+            Err(RecvTimeoutError::Disconnected) => fill(
+                &mut items,
+                Status::Panicked("the solver stopped unexpectedly".to_string()),
+            ),
+        }
+    }
+    items
+}
+
+fn solve_day(day: &Day, source: &InputSource, parts: &[Part], emit: &mut dyn FnMut(Row)) {
+    let input = match source.read(day.year, day.day) {
+        Ok(input) => input,
+        Err(error) => {
+            let status = Status::NoInput(error);
+            return parts
+                .iter()
+                .for_each(|&part| emit(row(day, part, status.clone())));
+        }
+    };
+    let input_hash = Some(input::hash(&input));
+    let input = match day.parse(&input) {
+        Ok(input) => input,
+        Err(error) => {
+            let status = Status::Invalid(error.to_string());
+            return parts.iter().for_each(|&part| {
+                emit(Row {
+                    input_hash: input_hash.clone(),
+                    ..row(day, part, status.clone())
+                })
+            });
+        }
+    };
+
+    parts.iter().for_each(|&part| {
+        let start = Instant::now();
+        let answer = day.solve(part, &input);
+        emit(Row {
+            answer: Some(answer),
+            time: Some(start.elapsed()),
+            input_hash: input_hash.clone(),
+            ..row(day, part, Status::Solved)
+        });
+    });
+}
+
+fn row(day: &Day, part: Part, status: Status) -> Row {
+    Row {
+        year: day.year,
+        day: day.day,
+        part,
+        answer: None,
+        time: None,
+        status,
+        expected: None,
+        input_hash: None,
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|it| it.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{thread, time::Duration};
+
+    use utils::{
+        input::InputSource,
+        registry::{Part, Registry},
+        solution::Solution,
+        ParseError,
+    };
+
+    use crate::{RunOptions, Selection, Status};
+
+    use super::solve;
+
+    struct Fragile;
+    impl Solution for Fragile {
+        type Input = u64;
+        type Part1 = u64;
+        type Part2 = u64;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            Ok(input.trim().parse().unwrap())
+        }
+        fn part1(input: &Self::Input) -> Self::Part1 {
+            *input
+        }
+        fn part2(input: &Self::Input) -> Self::Part2 {
+            thread::sleep(Duration::from_millis(*input));
+            *input
+        }
+    }
+
+    fn statuses(input: &str) -> Vec<(Part, Status)> {
+        let mut registry = Registry::new();
+        registry.add::<Fragile>(2022, 1);
+        let path =
+            std::env::temp_dir().join(format!("aoc-runner-{}-{}", input, std::process::id()));
+        std::fs::write(&path, input).unwrap();
+        let options = RunOptions {
+            jobs: 1,
+            timeout: Some(Duration::from_millis(200)),
+            ..RunOptions::default()
+        };
+
+        let summary = solve(
+            &Selection::all().days(&registry),
+            &InputSource::File(path.clone()),
+            &Part::all(),
+            &options,
+        );
+        std::fs::remove_file(path).unwrap();
+        summary
+            .rows
+            .into_iter()
+            .map(|it| (it.part, it.status))
+            .collect()
+    }
+
+    #[test]
+    fn isolate_panics_and_timeouts() {
+        assert_eq!(
+            statuses("1"),
+            vec![(Part::One, Status::Solved), (Part::Two, Status::Solved)]
+        );
+        assert_eq!(
+            statuses("5000"),
+            vec![
+                (Part::One, Status::Solved),
+                (Part::Two, Status::Timeout(Duration::from_millis(200)))
+            ]
+        );
+
+        let message =
+            "called `Result::unwrap()` on an `Err` value: ParseIntError { kind: InvalidDigit }";
+        assert_eq!(
+            statuses("x"),
+            vec![
+                (Part::One, Status::Panicked(message.to_string())),
+                (Part::Two, Status::Panicked(message.to_string()))
+            ]
+        );
+    }
+}
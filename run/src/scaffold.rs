@@ -0,0 +1,292 @@
+use std::{fs, path::Path};
+
+use toml_edit::{value, Array, DocumentMut, InlineTable, Item, Table, Value};
+
+const CARGO: &str = r#"[package]
+name = "year_{year}"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+utils = { workspace = true }
+"#;
+
+const LIB: &str = r#"use utils::registry::Registry;
+
+pub fn register(registry: &mut Registry) {
+}
+"#;
+
+const DAY: &str = r#"use utils::{
+    solution::{Solution, Unsolved},
+    ParseError,
+};
+
+pub struct Day{day};
+impl Solution for Day{day} {
+    type Input = String;
+    type Part1 = Unsolved;
+    type Part2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_string())
+    }
+    fn part1(_input: &Self::Input) -> Self::Part1 {
+        Unsolved
+    }
+    fn part2(_input: &Self::Input) -> Self::Part2 {
+        Unsolved
+    }
+}
+
+utils::examples! {
+    Day{day},
+    part1: ["example" => "-"],
+    part2: ["example" => "-"],
+}
+"#;
+
+pub fn scaffold(root: &Path, year: u16, day: u8) -> Result<Vec<String>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("invalid day {}, expected 1..=25", day));
+    }
+
+    let krate = format!("year_{}", year);
+    let module = format!("day{:02}", day);
+    let dir = root.join(&krate);
+    let mut changes = vec![];
+
+    if !dir.join("Cargo.toml").exists() {
+        write(
+            &dir.join("Cargo.toml"),
+            &CARGO.replace("{year}", &year.to_string()),
+        )?;
+        write(&dir.join("src/lib.rs"), LIB)?;
+        changes.push(format!("created {}", krate));
+    }
+
+    if add_to_workspace(&root.join("Cargo.toml"), &krate)? {
+        changes.push("added to Cargo.toml".to_string());
+    }
+    if add_line(
+        &root.join("src/main.rs"),
+        "::register(&mut registry);",
+        &format!("    {}::register(&mut registry);", krate),
+        Anchor::After("let mut registry"),
+    )? {
+        changes.push("registered in src/main.rs".to_string());
+    }
+
+    let day_dir = dir.join("src").join(&module);
+    if day_dir.join("mod.rs").exists() {
+        return Ok(changes);
+    }
+    write(
+        &day_dir.join("mod.rs"),
+        &DAY.replace("{day}", &format!("{:02}", day)),
+    )?;
+    write(&day_dir.join("example"), "")?;
+    changes.push(format!("created {}/src/{}", krate, module));
+
+    let lib = dir.join("src/lib.rs");
+    add_line(
+        &lib,
+        "mod day",
+        &format!("mod {};", module),
+        Anchor::Before("pub fn register"),
+    )?;
+    add_line(
+        &lib,
+        "registry.add::<",
+        &format!(
+            "    registry.add::<{}::Day{:02}>({}, {});",
+            module, day, year, day
+        ),
+        Anchor::After("pub fn register"),
+    )?;
+    changes.push(format!("registered in {}/src/lib.rs", krate));
+
+    Ok(changes)
+}
+
+fn write(path: &Path, content: &str) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .map_err(|error| format!("cannot create {}: {}", dir.display(), error))?;
+    }
+    fs::write(path, content).map_err(|error| format!("cannot write {}: {}", path.display(), error))
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|error| format!("cannot read {}: {}", path.display(), error))
+}
+
+fn add_to_workspace(path: &Path, krate: &str) -> Result<bool, String> {
+    let content = read(path)?;
+    let mut document: DocumentMut = content
+        .parse()
+        .map_err(|error| format!("invalid {}: {}", path.display(), error))?;
+
+    let members = document["workspace"]["members"]
+        .or_insert(value(Array::new()))
+        .as_array_mut()
+        .ok_or_else(|| format!("invalid workspace members in {}", path.display()))?;
+    if !members.iter().any(|it| it.as_str() == Some(krate)) {
+        members.push(krate);
+    }
+
+    let mut workspace = InlineTable::new();
+    workspace.insert("workspace", true.into());
+    add_dependency(&mut document["dependencies"], krate, workspace)
+        .ok_or_else(|| format!("invalid dependencies in {}", path.display()))?;
+
+    let mut local = InlineTable::new();
+    local.insert("path", krate.into());
+    add_dependency(&mut document["workspace"]["dependencies"], krate, local)
+        .ok_or_else(|| format!("invalid workspace dependencies in {}", path.display()))?;
+
+    let updated = document.to_string();
+    if updated == content {
+        return Ok(false);
+    }
+    write(path, &updated).map(|_| true)
+}
+
+fn add_dependency(item: &mut Item, krate: &str, dependency: InlineTable) -> Option<()> {
+    let table = item
+        .or_insert(Item::Table(Table::new()))
+        .as_table_like_mut()?;
+    if !table.contains_key(krate) {
+        table.insert(krate, Item::Value(Value::InlineTable(dependency)));
+    }
+    Some(())
+}
+
+fn add_line(path: &Path, pattern: &str, line: &str, first: Anchor) -> Result<bool, String> {
+    let content = read(path)?;
+    let mut lines: Vec<&str> = content.lines().collect();
+    if lines.iter().any(|it| it.trim() == line.trim()) {
+        return Ok(false);
+    }
+
+    let matching: Vec<usize> = (0..lines.len())
+        .filter(|&it| lines[it].contains(pattern))
+        .collect();
+    let anchor = |prefix: &str| lines.iter().position(|it| it.trim().starts_with(prefix));
+    let missing = || {
+        format!(
+            "cannot find where to add {} in {}",
+            line.trim(),
+            path.display()
+        )
+    };
+
+    match matching
+        .iter()
+        .rev()
+        .find(|&&it| lines[it].trim() < line.trim())
+    {
+        Some(index) => lines.insert(index + 1, line),
+        None if !matching.is_empty() => lines.insert(matching[0], line),
+        None => match first {
+            Anchor::After(prefix) => lines.insert(anchor(prefix).ok_or_else(missing)? + 1, line),
+            Anchor::Before(prefix) => {
+                let index = anchor(prefix).ok_or_else(missing)?;
+                lines.splice(index..index, [line, ""]);
+            }
+        },
+    }
+
+    write(path, &format!("{}\n", lines.join("\n"))).map(|_| true)
+}
+
+enum Anchor<'a> {
+    After(&'a str),
+    Before(&'a str),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf};
+
+    use super::scaffold;
+
+    const ROOT_CARGO: &str = r#"[package]
+name = "aoc"
+
+[dependencies]
+utils = { workspace = true }
+year_2022 = { workspace = true }
+
+[workspace]
+members = ["utils", "year_2022"]
+
+[workspace.dependencies]
+utils = { path = "utils" }
+year_2022 = { path = "year_2022" }
+"#;
+
+    const MAIN: &str = r#"fn registry() -> Registry {
+    let mut registry = Registry::new();
+    year_2022::register(&mut registry);
+    registry
+}
+"#;
+
+    fn temp_root(name: &str) -> PathBuf {
+        let root =
+            std::env::temp_dir().join(format!("aoc-scaffold-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(root.join("Cargo.toml"), ROOT_CARGO).unwrap();
+        fs::write(root.join("src/main.rs"), MAIN).unwrap();
+        root
+    }
+
+    #[test]
+    fn create_year_and_days() {
+        let root = temp_root("year");
+
+        scaffold(&root, 2024, 3).unwrap();
+        scaffold(&root, 2024, 1).unwrap();
+
+        let cargo = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        assert!(cargo.contains(r#"members = ["utils", "year_2022", "year_2024"]"#));
+        assert!(cargo.contains("year_2024 = { workspace = true }"));
+        assert!(cargo.contains(r#"year_2024 = { path = "year_2024" }"#));
+        assert_eq!(
+            fs::read_to_string(root.join("src/main.rs")).unwrap(),
+            MAIN.replace(
+                "    year_2022::register(&mut registry);\n",
+                "    year_2022::register(&mut registry);\n    year_2024::register(&mut registry);\n"
+            )
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("year_2024/src/lib.rs")).unwrap(),
+            "use utils::registry::Registry;\n\nmod day01;\nmod day03;\n\npub fn register(registry: &mut Registry) {\n    registry.add::<day01::Day01>(2024, 1);\n    registry.add::<day03::Day03>(2024, 3);\n}\n"
+        );
+        assert!(root.join("year_2024/src/day01/example").exists());
+        assert!(fs::read_to_string(root.join("year_2024/src/day03/mod.rs"))
+            .unwrap()
+            .contains("pub struct Day03;"));
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn idempotent() {
+        let root = temp_root("idempotent");
+        scaffold(&root, 2024, 3).unwrap();
+        fs::write(root.join("year_2024/src/day03/mod.rs"), "solved").unwrap();
+
+        let changes = scaffold(&root, 2024, 3).unwrap();
+
+        assert!(changes.is_empty());
+        assert_eq!(
+            fs::read_to_string(root.join("year_2024/src/day03/mod.rs")).unwrap(),
+            "solved"
+        );
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,166 @@
+use std::{ops::RangeInclusive, str::FromStr};
+
+use utils::registry::{Day, Part, Registry};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DayRange(RangeInclusive<u8>);
+impl DayRange {
+    pub fn contains(&self, day: u8) -> bool {
+        self.0.contains(&day)
+    }
+    pub fn iter(&self) -> RangeInclusive<u8> {
+        self.0.clone()
+    }
+}
+impl FromStr for DayRange {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let parse = |value: &str| {
+            value
+                .trim()
+                .parse::<u8>()
+                .map_err(|_| format!("invalid day {}", value))
+        };
+
+        let range = if let Some((from, to)) = input.split_once("..=") {
+            parse(from)?..=parse(to)?
+        } else if let Some((from, to)) = input.split_once("..") {
+            parse(from)?..=parse(to)?.saturating_sub(1)
+        } else {
+            let day = parse(input)?;
+            day..=day
+        };
+
+        if range.is_empty() {
+            return Err(format!("empty day range {}", input));
+        }
+
+        Ok(Self(range))
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Selection {
+    pub year: Option<u16>,
+    pub days: Option<DayRange>,
+    pub part: Option<Part>,
+}
+impl Selection {
+    pub fn all() -> Self {
+        Self::default()
+    }
+    pub fn days<'a>(&self, registry: &'a Registry) -> Vec<&'a Day> {
+        registry
+            .days()
+            .filter(|it| self.year.is_none_or(|year| year == it.year))
+            .filter(|it| self.days.as_ref().is_none_or(|days| days.contains(it.day)))
+            .collect()
+    }
+    pub fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => Part::all().to_vec(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod day_range {
+        use crate::DayRange;
+
+        #[test]
+        fn single_day() {
+            let range: DayRange = "16".parse().unwrap();
+            assert!(range.contains(16));
+            assert!(!range.contains(15));
+            assert!(!range.contains(17));
+        }
+
+        #[test]
+        fn inclusive_range() {
+            let range: DayRange = "1..=10".parse().unwrap();
+            assert!(range.contains(1));
+            assert!(range.contains(10));
+            assert!(!range.contains(11));
+        }
+
+        #[test]
+        fn exclusive_range() {
+            let range: DayRange = "1..10".parse().unwrap();
+            assert!(range.contains(9));
+            assert!(!range.contains(10));
+        }
+
+        #[test]
+        fn invalid_ranges() {
+            assert!("a".parse::<DayRange>().is_err());
+            assert!("1..=b".parse::<DayRange>().is_err());
+            assert!("10..=1".parse::<DayRange>().is_err());
+            assert!("1..1".parse::<DayRange>().is_err());
+        }
+    }
+
+    mod selection {
+        use utils::{
+            registry::{Part, Registry},
+            solution::{Solution, Unsolved},
+            ParseError,
+        };
+
+        use crate::Selection;
+
+        struct Nothing;
+        impl Solution for Nothing {
+            type Input = ();
+            type Part1 = Unsolved;
+            type Part2 = Unsolved;
+
+            fn parse(_input: &str) -> Result<Self::Input, ParseError> {
+                Ok(())
+            }
+            fn part1(_input: &Self::Input) -> Self::Part1 {
+                Unsolved
+            }
+            fn part2(_input: &Self::Input) -> Self::Part2 {
+                Unsolved
+            }
+        }
+
+        fn registry() -> Registry {
+            let mut registry = Registry::new();
+            (1..=3).for_each(|day| {
+                registry.add::<Nothing>(2022, day);
+                registry.add::<Nothing>(2023, day);
+            });
+            registry
+        }
+
+        #[test]
+        fn select_everything() {
+            let registry = registry();
+            assert_eq!(Selection::all().days(&registry).len(), 6);
+            assert_eq!(Selection::all().parts(), vec![Part::One, Part::Two]);
+        }
+
+        #[test]
+        fn select_year_days_and_part() {
+            let registry = registry();
+            let selection = Selection {
+                year: Some(2023),
+                days: Some("2..=3".parse().unwrap()),
+                part: Some(Part::Two),
+            };
+
+            let days: Vec<(u16, u8)> = selection
+                .days(&registry)
+                .into_iter()
+                .map(|it| (it.year, it.day))
+                .collect();
+
+            assert_eq!(days, vec![(2023, 2), (2023, 3)]);
+            assert_eq!(selection.parts(), vec![Part::Two]);
+        }
+    }
+}
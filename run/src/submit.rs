@@ -0,0 +1,399 @@
+use std::{
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+use utils::registry::Part;
+
+use crate::Http;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    Wait(u64),
+    AlreadySolved,
+    Unknown(String),
+}
+impl Verdict {
+    pub fn parse(response: &str) -> Self {
+        let text = article(response);
+        let lower = text.to_lowercase();
+
+        if lower.contains("that's the right answer") {
+            Verdict::Correct
+        } else if lower.contains("too high") {
+            Verdict::TooHigh
+        } else if lower.contains("too low") {
+            Verdict::TooLow
+        } else if lower.contains("not the right answer") {
+            Verdict::Wrong
+        } else if lower.contains("answer too recently") {
+            Verdict::Wait(wait_seconds(&lower))
+        } else if lower.contains("already complete") {
+            Verdict::AlreadySolved
+        } else {
+            Verdict::Unknown(text.trim().to_string())
+        }
+    }
+    fn is_recorded(&self) -> bool {
+        matches!(
+            self,
+            Verdict::Correct | Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong
+        )
+    }
+}
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "wrong, too high"),
+            Verdict::TooLow => write!(f, "wrong, too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::Wait(seconds) => write!(f, "too soon, wait {}s", seconds),
+            Verdict::AlreadySolved => write!(f, "already solved"),
+            Verdict::Unknown(text) => write!(f, "unknown response: {}", text),
+        }
+    }
+}
+
+fn article(response: &str) -> String {
+    let body = response
+        .split_once("<article")
+        .and_then(|(_, it)| it.split_once('>'))
+        .map(|(_, it)| it.split("</article>").next().unwrap_or(it))
+        .unwrap_or(response);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    body.chars().for_each(|char| match char {
+        '<' => in_tag = true,
+        '>' => in_tag = false,
+        char if !in_tag => text.push(char),
+        _ => {}
+    });
+    text
+}
+
+fn wait_seconds(text: &str) -> u64 {
+    let Some((_, rest)) = text.split_once("you have ") else {
+        return 0;
+    };
+    rest.split_whitespace()
+        .take_while(|it| !it.starts_with("left"))
+        .filter_map(|it| {
+            let (value, unit) = it.split_at(it.find(|c: char| !c.is_ascii_digit())?);
+            let value: u64 = value.parse().ok()?;
+            match unit {
+                "h" => Some(value * 3600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum()
+}
+
+fn level(part: Part) -> u8 {
+    match part {
+        Part::One => 1,
+        Part::Two => 2,
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct History {
+    pub submissions: Vec<Submission>,
+}
+impl History {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(path)
+            .map_err(|error| format!("cannot read history {}: {}", path.display(), error))?;
+        serde_json::from_str(&content)
+            .map_err(|error| format!("invalid history {}: {}", path.display(), error))
+    }
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let content = serde_json::to_string_pretty(self).map_err(|error| error.to_string())?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .map_err(|error| format!("cannot create {}: {}", dir.display(), error))?;
+        }
+        fs::write(path, content)
+            .map_err(|error| format!("cannot write history {}: {}", path.display(), error))
+    }
+    pub fn record(&mut self, year: u16, day: u8, part: Part, answer: &str, verdict: Verdict) {
+        self.submissions.push(Submission {
+            year,
+            day,
+            part: level(part),
+            answer: answer.to_string(),
+            verdict,
+        });
+    }
+    pub fn check(
+        &self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &str,
+    ) -> Result<Vec<String>, String> {
+        let submissions: Vec<&Submission> = self
+            .submissions
+            .iter()
+            .filter(|it| it.year == year && it.day == day && it.part == level(part))
+            .collect();
+
+        if let Some(correct) = submissions.iter().find(|it| it.verdict == Verdict::Correct) {
+            return Err(format!("already solved with {}", correct.answer));
+        }
+        if let Some(known) = submissions.iter().find(|it| it.answer == answer) {
+            return Err(format!(
+                "{} was already submitted: {}",
+                answer, known.verdict
+            ));
+        }
+
+        let Ok(value) = answer.parse::<i64>() else {
+            return Ok(vec![]);
+        };
+        Ok(submissions
+            .iter()
+            .filter_map(|it| {
+                let bound = it.answer.parse::<i64>().ok()?;
+                match it.verdict {
+                    Verdict::TooHigh if value >= bound => Some(format!(
+                        "{} is not lower than {}, which was too high",
+                        value, bound
+                    )),
+                    Verdict::TooLow if value <= bound => Some(format!(
+                        "{} is not higher than {}, which was too low",
+                        value, bound
+                    )),
+                    _ => None,
+                }
+            })
+            .collect())
+    }
+}
+
+pub struct Submitter<H: Http> {
+    pub http: H,
+    pub base_url: String,
+    pub session: String,
+    pub history: PathBuf,
+}
+impl<H: Http> Submitter<H> {
+    pub fn url(&self, year: u16, day: u8) -> String {
+        format!(
+            "{}/{}/day/{}/answer",
+            self.base_url.trim_end_matches('/'),
+            year,
+            day
+        )
+    }
+    pub fn submit(&self, year: u16, day: u8, part: Part, answer: &str) -> Result<Verdict, String> {
+        let answer = answer.trim();
+        if answer.is_empty() || answer.contains('\n') {
+            return Err("only single line answers can be submitted".to_string());
+        }
+
+        let mut history = History::load(&self.history)?;
+        history
+            .check(year, day, part, answer)?
+            .iter()
+            .for_each(|warning| println!("warning: {}", warning));
+
+        let level = level(part).to_string();
+        let response = self.http.post(
+            &self.url(year, day),
+            &self.session,
+            &[("level", &level), ("answer", answer)],
+        )?;
+
+        let verdict = Verdict::parse(&response);
+        if verdict.is_recorded() {
+            history.record(year, day, part, answer, verdict.clone());
+            if let Err(error) = history.save(&self.history) {
+                println!("warning: the answer was submitted but {}", error);
+            }
+        }
+        Ok(verdict)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, fs, path::PathBuf};
+
+    use utils::registry::Part;
+
+    use crate::Http;
+
+    use super::{History, Submitter, Verdict};
+
+    struct Stub {
+        response: &'static str,
+        requests: RefCell<Vec<(String, String)>>,
+    }
+    impl Http for &Stub {
+        fn get(&self, _url: &str, _session: &str) -> Result<String, String> {
+            Err("unexpected get".to_string())
+        }
+        fn post(&self, url: &str, _session: &str, form: &[(&str, &str)]) -> Result<String, String> {
+            let form: Vec<String> = form.iter().map(|(k, v)| format!("{}={}", k, v)).collect();
+            self.requests
+                .borrow_mut()
+                .push((url.to_string(), form.join("&")));
+            Ok(self.response.to_string())
+        }
+    }
+
+    fn temp_file(name: &str) -> PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("aoc-submit-{}-{}.json", name, std::process::id()));
+        let _ = fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn parse_verdicts() {
+        let page = |text: &str| {
+            format!(
+                "<html><main><article><p>{}</p></article></main></html>",
+                text
+            )
+        };
+
+        assert_eq!(
+            Verdict::parse(&page(
+                "That's the right answer! You are <em>one gold star</em> closer."
+            )),
+            Verdict::Correct
+        );
+        assert_eq!(
+            Verdict::parse(&page(
+                "That's not the right answer; your answer is too high."
+            )),
+            Verdict::TooHigh
+        );
+        assert_eq!(
+            Verdict::parse(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            Verdict::parse(&page("That's not the right answer. If you're stuck, ...")),
+            Verdict::Wrong
+        );
+        assert_eq!(
+            Verdict::parse(&page(
+                "You gave an answer too recently; you have 1m 23s left to wait."
+            )),
+            Verdict::Wait(83)
+        );
+        assert_eq!(
+            Verdict::parse(&page(
+                "You don't seem to be solving the right level. Did you already complete it?"
+            )),
+            Verdict::AlreadySolved
+        );
+    }
+
+    #[test]
+    fn refuse_known_answers() {
+        let mut history = History::default();
+        history.record(2022, 1, Part::One, "10", Verdict::TooHigh);
+
+        assert!(history.check(2022, 1, Part::One, "10").is_err());
+        assert_eq!(history.check(2022, 1, Part::Two, "10"), Ok(vec![]));
+
+        history.record(2022, 1, Part::One, "5", Verdict::Correct);
+        assert!(history.check(2022, 1, Part::One, "7").is_err());
+    }
+
+    #[test]
+    fn warn_on_contradicting_bounds() {
+        let mut history = History::default();
+        history.record(2022, 1, Part::One, "100", Verdict::TooHigh);
+        history.record(2022, 1, Part::One, "10", Verdict::TooLow);
+
+        assert_eq!(history.check(2022, 1, Part::One, "50").unwrap().len(), 0);
+        assert_eq!(history.check(2022, 1, Part::One, "150").unwrap().len(), 1);
+        assert_eq!(history.check(2022, 1, Part::One, "5").unwrap().len(), 1);
+    }
+
+    #[test]
+    fn submit_and_record() {
+        let path = temp_file("record");
+        let stub = Stub {
+            response:
+                "<article><p>That's not the right answer; your answer is too low.</p></article>",
+            requests: RefCell::new(vec![]),
+        };
+        let submitter = Submitter {
+            http: &stub,
+            base_url: "http://stub".to_string(),
+            session: "secret".to_string(),
+            history: path.clone(),
+        };
+
+        let verdict = submitter.submit(2023, 4, Part::Two, "42\n");
+
+        assert_eq!(verdict, Ok(Verdict::TooLow));
+        assert_eq!(
+            stub.requests.borrow().as_slice(),
+            [(
+                "http://stub/2023/day/4/answer".to_string(),
+                "level=2&answer=42".to_string()
+            )]
+        );
+        assert!(submitter.submit(2023, 4, Part::Two, "42").is_err());
+        assert_eq!(stub.requests.borrow().len(), 1);
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn create_the_history_directory() {
+        let stub = Stub {
+            response: "<article><p>That's the right answer!</p></article>",
+            requests: RefCell::new(vec![]),
+        };
+        let dir = temp_file("missing");
+        let _ = fs::remove_dir_all(&dir);
+        let submitter = Submitter {
+            http: &stub,
+            base_url: "http://stub".to_string(),
+            session: "secret".to_string(),
+            history: dir.join("history.json"),
+        };
+
+        assert_eq!(
+            submitter.submit(2023, 4, Part::One, "42"),
+            Ok(Verdict::Correct)
+        );
+        assert_eq!(stub.requests.borrow().len(), 1);
+        assert_eq!(
+            History::load(&submitter.history).unwrap().submissions.len(),
+            1
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,310 @@
+use std::{fmt::Display, time::Duration};
+
+use utils::{answer::Answer, registry::Part};
+
+use crate::bench::format_duration;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Status {
+    Solved,
+    Invalid(String),
+    NoInput(String),
+    Timeout(Duration),
+    Panicked(String),
+}
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Solved => write!(f, "ok"),
+            Status::Invalid(_) => write!(f, "invalid"),
+            Status::NoInput(_) => write!(f, "no input"),
+            Status::Timeout(_) => write!(f, "timeout"),
+            Status::Panicked(message) => write!(f, "panicked: {}", message),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Row {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub answer: Option<Answer>,
+    pub time: Option<Duration>,
+    pub status: Status,
+    pub expected: Option<String>,
+    pub input_hash: Option<String>,
+}
+impl Row {
+    pub fn is_wrong(&self) -> bool {
+        match (&self.answer, &self.expected) {
+            (Some(answer), Some(expected)) => !answer.matches(expected),
+            _ => false,
+        }
+    }
+    pub fn error(&self) -> Option<String> {
+        let part = format!("{} day {:02} part {}", self.year, self.day, self.part);
+        match &self.status {
+            Status::Solved if self.is_wrong() => Some(format!(
+                "{} answered {}, expected {}",
+                part,
+                self.answer_text().unwrap_or_default(),
+                self.expected.as_deref().unwrap_or_default().trim()
+            )),
+            Status::Solved => None,
+            Status::Invalid(error) | Status::NoInput(error) => Some(error.clone()),
+            Status::Timeout(limit) => Some(format!(
+                "{} timed out after {}",
+                part,
+                format_duration(limit.as_nanos() as u64)
+            )),
+            Status::Panicked(message) => Some(format!("{} panicked: {}", part, message)),
+        }
+    }
+    /// The answer as it would be submitted, or as printed when it cannot be.
+    pub fn answer_text(&self) -> Option<String> {
+        self.answer
+            .as_ref()
+            .map(|it| it.submission().unwrap_or_else(|| it.to_string()))
+    }
+    fn failure(&self) -> Option<&'static str> {
+        match self.status {
+            Status::Solved if self.is_wrong() => Some("wrong"),
+            Status::Solved => None,
+            Status::Invalid(_) | Status::NoInput(_) => Some("error"),
+            Status::Timeout(_) => Some("timeout"),
+            Status::Panicked(_) => Some("panic"),
+        }
+    }
+    fn answer_cell(&self) -> String {
+        match &self.answer {
+            None => "-".to_string(),
+            Some(_) if self.is_multiline() => "(see below)".to_string(),
+            Some(answer) => answer.ocr().unwrap_or_else(|| answer.to_string()),
+        }
+    }
+    fn status_cell(&self) -> String {
+        if self.is_wrong() {
+            "wrong".to_string()
+        } else {
+            self.status.to_string()
+        }
+    }
+    fn is_multiline(&self) -> bool {
+        self.answer
+            .as_ref()
+            .is_some_and(|it| it.ocr().is_none() && it.to_string().contains('\n'))
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Summary {
+    pub rows: Vec<Row>,
+}
+impl Summary {
+    pub fn errors(&self) -> Vec<String> {
+        let mut errors: Vec<String> = self.rows.iter().filter_map(Row::error).collect();
+        errors.dedup();
+        errors
+    }
+    /// Describes how many days got a wrong answer, an error, a timeout or a
+    /// panic, or `None` when every day was solved.
+    pub fn failures(&self) -> Option<String> {
+        let kinds = [
+            ("wrong", "answered wrong", "answered wrong"),
+            ("error", "failed with an error", "failed with errors"),
+            ("timeout", "timed out", "timed out"),
+            ("panic", "panicked", "panicked"),
+        ];
+        let counts: Vec<String> = kinds
+            .iter()
+            .filter_map(|&(kind, one, many)| {
+                let mut days: Vec<(u16, u8)> = self
+                    .rows
+                    .iter()
+                    .filter(|it| it.failure() == Some(kind))
+                    .map(|it| (it.year, it.day))
+                    .collect();
+                days.dedup();
+                match days.len() {
+                    0 => None,
+                    1 => Some(format!("1 day {}", one)),
+                    count => Some(format!("{} days {}", count, many)),
+                }
+            })
+            .collect();
+
+        (!counts.is_empty()).then(|| counts.join(", "))
+    }
+}
+impl Display for Summary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let answers: Vec<String> = self.rows.iter().map(Row::answer_cell).collect();
+        let width = answers
+            .iter()
+            .map(|it| it.chars().count())
+            .fold(6, usize::max)
+            + 2;
+
+        writeln!(
+            f,
+            "{:<6}{:<5}{:<6}{:<width$}{:<11}Status",
+            "Year", "Day", "Part", "Answer", "Time"
+        )?;
+        for (row, answer) in self.rows.iter().zip(answers) {
+            let time = row
+                .time
+                .map_or("-".to_string(), |it| format_duration(it.as_nanos() as u64));
+            writeln!(
+                f,
+                "{:<6}{:<5}{:<6}{:<width$}{:<11}{}",
+                row.year,
+                format!("{:02}", row.day),
+                row.part.to_string(),
+                answer,
+                time,
+                row.status_cell()
+            )?;
+        }
+
+        for row in self.rows.iter().filter(|it| it.is_multiline()) {
+            writeln!(f)?;
+            writeln!(
+                f,
+                "Year {} - Day {:02} - Part{}",
+                row.year, row.day, row.part
+            )?;
+            writeln!(f, "{}", row.answer.as_ref().unwrap())?;
+        }
+        for error in self.errors() {
+            writeln!(f)?;
+            writeln!(f, "error: {}", error)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use utils::{answer::Answer, registry::Part};
+
+    use super::{Row, Status, Summary};
+
+    fn row(day: u8, part: Part, answer: Option<Answer>, status: Status) -> Row {
+        Row {
+            year: 2022,
+            day,
+            part,
+            time: answer.as_ref().map(|_| Duration::from_micros(12)),
+            answer,
+            status,
+            expected: None,
+            input_hash: None,
+        }
+    }
+
+    #[test]
+    fn print_table() {
+        let error = "2022 day 02, line 1, column 1: bad".to_string();
+        let summary = Summary {
+            rows: vec![
+                Row {
+                    expected: Some("24000".to_string()),
+                    ..row(1, Part::One, Some(Answer::Int(24000)), Status::Solved)
+                },
+                row(
+                    1,
+                    Part::Two,
+                    Some(Answer::Text("a\nb".into())),
+                    Status::Solved,
+                ),
+                row(2, Part::One, None, Status::Invalid(error.clone())),
+                row(2, Part::Two, None, Status::Invalid(error.clone())),
+            ],
+        };
+
+        assert_eq!(summary.errors(), vec![error]);
+        assert_eq!(
+            summary.failures().as_deref(),
+            Some("1 day failed with an error")
+        );
+        assert_eq!(
+            summary.to_string(),
+            [
+                "Year  Day  Part  Answer       Time       Status",
+                "2022  01   1     24000        12.00µs    ok",
+                "2022  01   2     (see below)  12.00µs    ok",
+                "2022  02   1     -            -          invalid",
+                "2022  02   2     -            -          invalid",
+                "",
+                "Year 2022 - Day 01 - Part2",
+                "a\nb",
+                "",
+                "error: 2022 day 02, line 1, column 1: bad",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn wrong_answers() {
+        let wrong = Row {
+            expected: Some("25\n".to_string()),
+            ..row(3, Part::Two, Some(Answer::Int(24)), Status::Solved)
+        };
+        let summary = Summary { rows: vec![wrong] };
+
+        assert!(summary.rows[0].is_wrong());
+        assert_eq!(summary.failures().as_deref(), Some("1 day answered wrong"));
+        assert_eq!(
+            summary.errors(),
+            vec!["2022 day 03 part 2 answered 24, expected 25"]
+        );
+        assert!(summary.to_string().contains("24      12.00µs    wrong"));
+    }
+
+    #[test]
+    fn report_the_whole_wrong_multiline_answer() {
+        let wrong = Row {
+            expected: Some("a\nc\n".to_string()),
+            ..row(
+                1,
+                Part::Two,
+                Some(Answer::Text("a\nb".to_string())),
+                Status::Solved,
+            )
+        };
+
+        assert_eq!(
+            wrong.error().as_deref(),
+            Some("2022 day 01 part 2 answered a\nb, expected a\nc")
+        );
+    }
+
+    #[test]
+    fn count_failures_by_kind() {
+        let wrong = |day| Row {
+            expected: Some("25".to_string()),
+            ..row(day, Part::One, Some(Answer::Int(24)), Status::Solved)
+        };
+        let summary = Summary {
+            rows: vec![
+                wrong(1),
+                wrong(2),
+                row(3, Part::One, None, Status::Timeout(Duration::from_secs(1))),
+                row(3, Part::Two, None, Status::Timeout(Duration::from_secs(1))),
+                row(4, Part::Two, None, Status::Panicked("oops".to_string())),
+                row(5, Part::One, Some(Answer::Int(1)), Status::Solved),
+            ],
+        };
+
+        assert_eq!(
+            summary.failures().as_deref(),
+            Some("2 days answered wrong, 1 day timed out, 1 day panicked")
+        );
+        assert_eq!(Summary { rows: vec![] }.failures(), None);
+    }
+}
@@ -0,0 +1,187 @@
+use utils::{
+    input::InputSource,
+    registry::{Day, Part, Registry},
+};
+
+use crate::{answers::Answers, runner, RunOptions, Selection, Status, Summary};
+
+/// Solves the days and keeps the parts that have both an input and a stored
+/// answer, returning them with the number of parts left out.
+pub fn check(
+    days: &[&Day],
+    source: &InputSource,
+    parts: &[Part],
+    answers: &Answers,
+    options: &RunOptions,
+) -> (Summary, usize) {
+    let mut summary = runner::solve(days, source, parts, options);
+    summary.rows.iter_mut().for_each(|row| {
+        row.expected = answers.get(row.year, row.day, row.part).map(str::to_string)
+    });
+
+    let (rows, missing): (Vec<_>, Vec<_>) = summary
+        .rows
+        .into_iter()
+        .partition(|row| row.expected.is_some() && !matches!(row.status, Status::NoInput(_)));
+    (Summary { rows }, missing.len())
+}
+
+pub fn verify(
+    registry: &Registry,
+    selection: &Selection,
+    source: &InputSource,
+    answers: &Answers,
+    options: &RunOptions,
+) -> Result<(), String> {
+    let days = selection.days(registry);
+    if days.is_empty() {
+        return Err("no registered day matches the selection".to_string());
+    }
+
+    let (summary, missing) = check(&days, source, &selection.parts(), answers, options);
+    print!("{}", summary);
+    if missing > 0 {
+        println!();
+        println!("{} parts have no input or stored answer", missing);
+    }
+
+    match summary.failures() {
+        None => Ok(()),
+        Some(failures) => Err(failures),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf, time::Duration};
+
+    use utils::{
+        input::InputSource,
+        parse::ints,
+        registry::{Part, Registry},
+        solution::Solution,
+        ParseError,
+    };
+
+    use crate::{answers::Answers, RunOptions, Status, Summary};
+
+    use super::check;
+
+    struct Sum;
+    impl Solution for Sum {
+        type Input = Vec<u32>;
+        type Part1 = u32;
+        type Part2 = u32;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            ints(input)
+        }
+        fn part1(input: &Self::Input) -> Self::Part1 {
+            input.iter().sum()
+        }
+        fn part2(input: &Self::Input) -> Self::Part2 {
+            if input.contains(&0) {
+                panic!("empty product");
+            }
+            input.iter().product()
+        }
+    }
+
+    fn inputs(name: &str, days: &[(u8, &str)]) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-verify-{}-{}", name, std::process::id()));
+        fs::create_dir_all(dir.join("2022")).unwrap();
+        days.iter().for_each(|(day, input)| {
+            fs::write(InputSource::path(&dir, 2022, *day), input).unwrap();
+        });
+        dir
+    }
+
+    fn verify(name: &str, days: &[(u8, &str)], answers: &Answers) -> (Summary, usize) {
+        let mut registry = Registry::new();
+        (1..=3).for_each(|day| registry.add::<Sum>(2022, day));
+        let dir = inputs(name, days);
+        let options = RunOptions {
+            jobs: 1,
+            timeout: Some(Duration::from_secs(5)),
+            ..RunOptions::default()
+        };
+
+        let days = registry.days().collect::<Vec<_>>();
+        let checked = check(
+            &days,
+            &InputSource::Dir(dir.clone()),
+            &Part::all(),
+            answers,
+            &options,
+        );
+        fs::remove_dir_all(dir).unwrap();
+        checked
+    }
+
+    fn statuses(summary: &Summary) -> Vec<(u8, Part, Status, bool)> {
+        summary
+            .rows
+            .iter()
+            .map(|it| (it.day, it.part, it.status.clone(), it.is_wrong()))
+            .collect()
+    }
+
+    #[test]
+    fn compare_parts_with_answers() {
+        let mut answers = Answers::default();
+        answers.insert(2022, 1, Part::One, "9".to_string());
+        answers.insert(2022, 1, Part::Two, "25\n".to_string());
+
+        let (summary, missing) = verify("compare", &[(1, "2,3,4\n")], &answers);
+
+        assert_eq!(
+            statuses(&summary),
+            vec![
+                (1, Part::One, Status::Solved, false),
+                (1, Part::Two, Status::Solved, true)
+            ]
+        );
+        assert_eq!(missing, 4);
+        assert_eq!(
+            summary.errors(),
+            vec!["2022 day 01 part 2 answered 24, expected 25"]
+        );
+    }
+
+    #[test]
+    fn missing_answers() {
+        let (summary, missing) = verify("missing", &[(1, "1")], &Answers::default());
+
+        assert!(summary.rows.is_empty());
+        assert_eq!(missing, 6);
+        assert_eq!(summary.failures(), None);
+    }
+
+    #[test]
+    fn keep_going_after_invalid_inputs_and_panics() {
+        let mut answers = Answers::default();
+        (1..=3).for_each(|day| {
+            answers.insert(2022, day, Part::One, "1".to_string());
+            answers.insert(2022, day, Part::Two, "0".to_string());
+        });
+
+        let (summary, missing) = verify("panics", &[(1, "1,-2"), (2, "1,0"), (3, "1")], &answers);
+
+        let statuses = statuses(&summary);
+        assert_eq!(missing, 0);
+        assert_eq!(
+            statuses[0].2,
+            Status::Invalid(
+                "2022 day 01, line 1, column 3: \"-2\": invalid digit found in string in \"1,-2\\n\""
+                    .to_string()
+            )
+        );
+        assert_eq!(statuses[3].2, Status::Panicked("empty product".to_string()));
+        assert_eq!(statuses[4], (3, Part::One, Status::Solved, false));
+        assert_eq!(statuses[5], (3, Part::Two, Status::Solved, true));
+        assert_eq!(
+            summary.failures().as_deref(),
+            Some("1 day answered wrong, 1 day failed with an error, 1 day panicked")
+        );
+    }
+}
@@ -0,0 +1,257 @@
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    path::{Path, PathBuf},
+    process::{Command, Output},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use serde::Deserialize;
+use utils::input::InputSource;
+
+type Snapshot = BTreeMap<PathBuf, (SystemTime, u64)>;
+
+#[derive(Clone, Debug)]
+pub struct Watch {
+    pub year: u16,
+    pub day: u8,
+    pub root: PathBuf,
+    pub inputs: PathBuf,
+    pub interval: Duration,
+    pub debounce: Duration,
+    pub release: bool,
+}
+impl Watch {
+    pub fn watch(&self) -> Result<(), String> {
+        let source = self.source_dir();
+        if !source.is_dir() {
+            return Err(format!("{} does not exist", source.display()));
+        }
+
+        println!("Watching {}", source.display());
+        let mut snapshot = self.snapshot();
+        let mut previous = self.cycle(&BTreeMap::new())?;
+        loop {
+            thread::sleep(self.interval);
+            if self.snapshot() == snapshot {
+                continue;
+            }
+            snapshot = self.settle();
+            previous = self.cycle(&previous)?;
+        }
+    }
+    fn source_dir(&self) -> PathBuf {
+        self.root
+            .join(format!("year_{}", self.year))
+            .join("src")
+            .join(format!("day{:02}", self.day))
+    }
+    fn snapshot(&self) -> Snapshot {
+        let mut snapshot = Snapshot::new();
+        collect(&self.source_dir(), &mut snapshot);
+        collect(
+            &InputSource::path(&self.inputs, self.year, self.day),
+            &mut snapshot,
+        );
+        snapshot
+    }
+    fn settle(&self) -> Snapshot {
+        let mut snapshot = self.snapshot();
+        loop {
+            thread::sleep(self.debounce);
+            let next = self.snapshot();
+            if next == snapshot {
+                return snapshot;
+            }
+            snapshot = next;
+        }
+    }
+    fn cycle(
+        &self,
+        previous: &BTreeMap<String, String>,
+    ) -> Result<BTreeMap<String, String>, String> {
+        println!();
+        println!("Year {} - Day {:02}", self.year, self.day);
+
+        let build = self.cargo(&["build", "--bin", "aoc"])?;
+        if !build.status.success() {
+            println!("build failed:");
+            print!("{}", String::from_utf8_lossy(&build.stderr));
+            return Ok(previous.clone());
+        }
+
+        let package = format!("year_{}", self.year);
+        let filter = format!("day{:02}::", self.day);
+        let tests = self.cargo(&["test", "-p", &package, &filter])?;
+        if tests.status.success() {
+            println!("examples: ok");
+        } else {
+            println!("examples: FAILED");
+            print!("{}", String::from_utf8_lossy(&tests.stdout));
+        }
+
+        let (year, day) = (self.year.to_string(), self.day.to_string());
+        let inputs = self.inputs.to_string_lossy();
+        let run = self.cargo(&[
+            "run", "--bin", "aoc", "--", "run", "--year", &year, "--day", &day, "--inputs",
+            &inputs, "--format", "json",
+        ])?;
+        let answers = answers(&String::from_utf8_lossy(&run.stdout));
+        if answers.is_empty() {
+            print!("{}", String::from_utf8_lossy(&run.stdout));
+            print!("{}", String::from_utf8_lossy(&run.stderr));
+        }
+        print!("{}", diff(previous, &answers));
+        Ok(answers)
+    }
+    fn cargo(&self, args: &[&str]) -> Result<Output, String> {
+        let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+        let mut command = Command::new(cargo);
+        command.current_dir(&self.root).args(&args[..1]).arg("-q");
+        if self.release {
+            command.arg("--release");
+        }
+        command
+            .args(&args[1..])
+            .output()
+            .map_err(|error| format!("cannot run cargo: {}", error))
+    }
+}
+
+fn collect(path: &Path, snapshot: &mut Snapshot) {
+    let Ok(metadata) = fs::metadata(path) else {
+        return;
+    };
+    if metadata.is_dir() {
+        fs::read_dir(path)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .for_each(|entry| collect(&entry.path(), snapshot));
+    } else if let Ok(modified) = metadata.modified() {
+        snapshot.insert(path.to_path_buf(), (modified, metadata.len()));
+    }
+}
+
+#[derive(Deserialize)]
+struct Report {
+    results: Vec<PartResult>,
+}
+
+#[derive(Deserialize)]
+struct PartResult {
+    part: u8,
+    status: String,
+    answer: Option<String>,
+    error: Option<String>,
+}
+
+fn answers(output: &str) -> BTreeMap<String, String> {
+    let Ok(report) = serde_json::from_str::<Report>(output) else {
+        return BTreeMap::new();
+    };
+
+    report
+        .results
+        .into_iter()
+        .map(|result| {
+            let answer = result.answer.unwrap_or_default();
+            let value = match result.status.as_str() {
+                "ok" => answer,
+                "wrong" => format!("{} (wrong)", answer),
+                _ => result.error.unwrap_or(result.status),
+            };
+            (format!("Part{}", result.part), value)
+        })
+        .collect()
+}
+
+fn diff(previous: &BTreeMap<String, String>, answers: &BTreeMap<String, String>) -> String {
+    answers
+        .iter()
+        .map(|(part, answer)| match previous.get(part) {
+            None => format!("{}: {}\n", part, answer),
+            Some(old) if old == answer => format!("{}: {} (unchanged)\n", part, answer),
+            Some(old) => format!("{}: {} (was {})\n", part, answer, old),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, fs, time::Duration};
+
+    use super::{answers, diff, Watch};
+
+    #[test]
+    fn read_answers_from_the_report() {
+        let report = |status: &str, answer: Option<&str>, error: Option<&str>| {
+            serde_json::json!({
+                "results": [
+                    {"year": 2022, "day": 10, "part": 1, "status": "wrong", "answer": "13140"},
+                    {"year": 2022, "day": 10, "part": 2, "status": status, "answer": answer, "error": error},
+                ]
+            })
+            .to_string()
+        };
+
+        let answers = answers(&report("ok", Some("##..##..\n###...##"), None));
+
+        assert_eq!(answers["Part1"], "13140 (wrong)");
+        assert_eq!(answers["Part2"], "##..##..\n###...##");
+        let panicked = report("panicked", None, Some("2022 day 10 part 2 panicked: oops"));
+        assert_eq!(
+            super::answers(&panicked)["Part2"],
+            "2022 day 10 part 2 panicked: oops"
+        );
+        assert_eq!(
+            super::answers(&report("timeout", None, None))["Part2"],
+            "timeout"
+        );
+        assert!(super::answers("error: no input").is_empty());
+    }
+
+    #[test]
+    fn compare_with_the_last_run() {
+        let previous = BTreeMap::from([("Part1".to_string(), "1".to_string())]);
+        let actual = BTreeMap::from([
+            ("Part1".to_string(), "2".to_string()),
+            ("Part2".to_string(), "3".to_string()),
+        ]);
+
+        assert_eq!(diff(&previous, &actual), "Part1: 2 (was 1)\nPart2: 3\n");
+        assert_eq!(
+            diff(&actual, &actual),
+            "Part1: 2 (unchanged)\nPart2: 3 (unchanged)\n"
+        );
+    }
+
+    #[test]
+    fn detect_changes() {
+        let root = std::env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        let day = root.join("year_2023").join("src").join("day16");
+        fs::create_dir_all(&day).unwrap();
+        fs::write(day.join("mod.rs"), "").unwrap();
+        let watch = Watch {
+            year: 2023,
+            day: 16,
+            root: root.clone(),
+            inputs: root.join("inputs"),
+            interval: Duration::from_millis(10),
+            debounce: Duration::from_millis(10),
+            release: false,
+        };
+
+        let before = watch.snapshot();
+        fs::write(day.join("example"), "1").unwrap();
+        let after = watch.settle();
+        fs::create_dir_all(root.join("inputs").join("2023")).unwrap();
+        fs::write(root.join("inputs").join("2023").join("day16.txt"), "2").unwrap();
+
+        assert_eq!(before.len(), 1);
+        assert_eq!(after.len(), 2);
+        assert_eq!(watch.snapshot().len(), 3);
+        fs::remove_dir_all(root).unwrap();
+    }
+}
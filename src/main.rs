@@ -1,3 +1,461 @@
-fn main() {
-    println!("Welcome to the league of AOC");
+use std::{
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::Duration,
+};
+
+use clap::{Args, Parser, Subcommand};
+use run::{
+    Answers, Config, DayRange, Fetcher, Format, Report, RunOptions, Selection, Submitter, UreqHttp,
+    Verdict, Watch, BASE_URL, CONFIG,
+};
+use utils::{
+    input::InputSource,
+    registry::{Part, Registry},
+};
+
+/// Welcome to the league of AOC
+#[derive(Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Configuration file, overridden by AOC_* environment variables
+    /// [default: .aoc.toml if present]
+    #[arg(long, global = true)]
+    config: Option<PathBuf>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the selected solutions
+    Run(RunArgs),
+    /// Check the solutions against the stored answers
+    Verify(VerifyArgs),
+    /// Time parse and parts of the selected solutions
+    Bench(BenchArgs),
+    /// Download the puzzle inputs into the inputs directory
+    Fetch(FetchArgs),
+    /// Solve a part and submit its answer
+    Submit(SubmitArgs),
+    /// Generate the skeleton of a new day, creating its year crate if needed
+    New(NewArgs),
+    /// Rebuild and run a day whenever its sources or input change
+    Watch(WatchArgs),
+    /// Inspect the configuration
+    #[command(subcommand)]
+    Config(ConfigCommand),
+}
+
+#[derive(Subcommand)]
+enum ConfigCommand {
+    /// Print the effective configuration and where each value comes from
+    Show,
+}
+
+#[derive(Args)]
+struct RunArgs {
+    #[arg(long)]
+    year: Option<u16>,
+    /// A single day (16) or a range of days (1..=10, 1..11)
+    #[arg(long)]
+    day: Option<DayRange>,
+    #[arg(long)]
+    part: Option<Part>,
+    /// Run every registered day of every year
+    #[arg(long, conflicts_with_all = ["year", "day"])]
+    all: bool,
+    /// Read the input of a single day from this file, or from stdin with -
+    #[arg(long)]
+    input: Option<PathBuf>,
+    /// Directory containing the inputs as YEAR/dayNN.txt
+    #[arg(long, conflicts_with = "input")]
+    inputs: Option<PathBuf>,
+    /// How many days are solved concurrently, defaults to the number of CPUs
+    #[arg(long)]
+    jobs: Option<usize>,
+    /// Seconds after which a day is reported as timed out
+    #[arg(long, value_parser = run::seconds)]
+    timeout: Option<Duration>,
+    /// Directory containing the expected answers as YEAR.toml
+    #[arg(long)]
+    answers: Option<PathBuf>,
+    /// Report format: table, json or junit
+    #[arg(long)]
+    format: Option<Format>,
+    /// Write the report to this file instead of stdout
+    #[arg(long)]
+    output: Option<PathBuf>,
+}
+impl RunArgs {
+    fn source(&self, config: &Config) -> InputSource {
+        match &self.input {
+            Some(path) if path.as_os_str() == "-" => InputSource::Stdin,
+            Some(path) => InputSource::File(path.clone()),
+            None => InputSource::Dir(inputs(&self.inputs, config)),
+        }
+    }
+    fn selection(&self, config: &Config) -> Result<Selection, String> {
+        if self.all {
+            return Ok(Selection {
+                part: self.part,
+                ..Selection::all()
+            });
+        }
+        Ok(Selection {
+            year: Some(year(self.year, config)?),
+            days: self.day.clone(),
+            part: self.part,
+        })
+    }
+}
+#[derive(Args)]
+struct VerifyArgs {
+    #[arg(long)]
+    year: Option<u16>,
+    /// A single day (16) or a range of days (1..=10, 1..11)
+    #[arg(long)]
+    day: Option<DayRange>,
+    /// Directory containing the inputs as YEAR/dayNN.txt
+    #[arg(long)]
+    inputs: Option<PathBuf>,
+    /// Directory containing the answers as YEAR.toml
+    #[arg(long)]
+    answers: Option<PathBuf>,
+    /// How many days are solved concurrently, defaults to the number of CPUs
+    #[arg(long)]
+    jobs: Option<usize>,
+    /// Seconds after which a day is reported as timed out
+    #[arg(long, value_parser = run::seconds)]
+    timeout: Option<Duration>,
+}
+impl VerifyArgs {
+    fn selection(&self, config: &Config) -> Result<Selection, String> {
+        selection(self.year, &self.day, None, config)
+    }
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    #[arg(long)]
+    year: Option<u16>,
+    /// A single day (16) or a range of days (1..=10, 1..11)
+    #[arg(long)]
+    day: Option<DayRange>,
+    #[arg(long)]
+    part: Option<Part>,
+    /// How many times each step is executed
+    #[arg(long)]
+    iterations: Option<usize>,
+    /// Seconds after which benchmarking a day is given up
+    #[arg(long, value_parser = run::seconds)]
+    timeout: Option<Duration>,
+    /// Directory containing the inputs as YEAR/dayNN.txt
+    #[arg(long)]
+    inputs: Option<PathBuf>,
+    /// Write the JSON report to this file
+    #[arg(long)]
+    output: Option<PathBuf>,
+    /// Compare the medians with a previous JSON report
+    #[arg(long)]
+    compare: Option<PathBuf>,
+    /// Relative median slowdown reported as a regression
+    #[arg(long, default_value_t = 0.1)]
+    threshold: f64,
+}
+impl BenchArgs {
+    fn selection(&self, config: &Config) -> Result<Selection, String> {
+        selection(self.year, &self.day, self.part, config)
+    }
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    #[arg(long)]
+    year: Option<u16>,
+    /// A single day (16) or a range of days (1..=10, 1..11)
+    #[arg(long)]
+    day: DayRange,
+    /// Directory containing the inputs as YEAR/dayNN.txt
+    #[arg(long)]
+    inputs: Option<PathBuf>,
+    #[command(flatten)]
+    server: ServerArgs,
+    /// Minimum seconds between two requests
+    #[arg(long, default_value_t = 5)]
+    rate_limit: u64,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    #[arg(long)]
+    year: Option<u16>,
+    #[arg(long)]
+    day: u8,
+    #[arg(long)]
+    part: Part,
+    /// Directory containing the inputs as YEAR/dayNN.txt
+    #[arg(long)]
+    inputs: Option<PathBuf>,
+    /// File keeping every submitted answer and its outcome
+    /// [default: history.json in the answers directory]
+    #[arg(long)]
+    history: Option<PathBuf>,
+    #[command(flatten)]
+    server: ServerArgs,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    #[arg(long)]
+    year: Option<u16>,
+    #[arg(long)]
+    day: u8,
+}
+
+#[derive(Args)]
+struct WatchArgs {
+    #[arg(long)]
+    year: Option<u16>,
+    #[arg(long)]
+    day: u8,
+    /// Directory containing the inputs as YEAR/dayNN.txt
+    #[arg(long)]
+    inputs: Option<PathBuf>,
+    /// Milliseconds between two checks of the files
+    #[arg(long, default_value_t = 500)]
+    interval: u64,
+    /// Milliseconds without changes to wait for before rebuilding
+    #[arg(long, default_value_t = 300)]
+    debounce: u64,
+    /// Build and run with optimizations
+    #[arg(long)]
+    release: bool,
+}
+impl WatchArgs {
+    fn watch(&self, config: &Config) -> Result<Watch, String> {
+        Ok(Watch {
+            year: year(self.year, config)?,
+            day: self.day,
+            root: PathBuf::from("."),
+            inputs: inputs(&self.inputs, config),
+            interval: Duration::from_millis(self.interval),
+            debounce: Duration::from_millis(self.debounce),
+            release: self.release,
+        })
+    }
+}
+
+#[derive(Args)]
+struct ServerArgs {
+    /// File containing the session token, used when AOC_SESSION is not set
+    #[arg(long)]
+    session: Option<PathBuf>,
+    #[arg(long, default_value = BASE_URL)]
+    base_url: String,
+}
+
+impl ServerArgs {
+    fn session(&self, config: &Config) -> Result<String, String> {
+        let file = self.session.as_ref().unwrap_or(&config.session.value);
+        run::session(config.token.value.as_deref(), file)
+    }
+}
+
+fn year(year: Option<u16>, config: &Config) -> Result<u16, String> {
+    year.or(config.year.value)
+        .ok_or_else(|| format!("no year selected, pass --year or set year in {}", CONFIG))
+}
+
+/// Selects the configured year by default, or every year when none is
+/// configured and no days are given.
+fn selection(
+    year_arg: Option<u16>,
+    days: &Option<DayRange>,
+    part: Option<Part>,
+    config: &Config,
+) -> Result<Selection, String> {
+    let year = match days {
+        Some(_) => Some(year(year_arg, config)?),
+        None => year_arg.or(config.year.value),
+    };
+    Ok(Selection {
+        year,
+        days: days.clone(),
+        part,
+    })
+}
+
+fn inputs(inputs: &Option<PathBuf>, config: &Config) -> PathBuf {
+    inputs
+        .clone()
+        .unwrap_or_else(|| config.inputs.value.clone())
+}
+
+fn answers(answers: &Option<PathBuf>, config: &Config) -> PathBuf {
+    answers
+        .clone()
+        .unwrap_or_else(|| config.answers.value.clone())
+}
+
+fn registry() -> Registry {
+    let mut registry = Registry::new();
+    year_2022::register(&mut registry);
+    year_2023::register(&mut registry);
+    registry
+}
+
+fn run(args: &RunArgs, config: &Config) -> Result<(), String> {
+    let registry = registry();
+    let answers = Answers::load(&answers(&args.answers, config), &registry.years())?;
+    let options = RunOptions {
+        jobs: args.jobs.unwrap_or_else(run::default_jobs),
+        timeout: args.timeout.or(config.timeout.value),
+        format: args.format.unwrap_or(config.format.value),
+        output: args.output.clone(),
+    };
+
+    run::run(
+        &registry,
+        &args.selection(config)?,
+        &args.source(config),
+        &answers,
+        &options,
+    )
+}
+
+fn verify(args: &VerifyArgs, config: &Config) -> Result<(), String> {
+    let registry = registry();
+    let answers = Answers::load(&answers(&args.answers, config), &registry.years())?;
+    let source = InputSource::Dir(inputs(&args.inputs, config));
+
+    let options = RunOptions {
+        jobs: args.jobs.unwrap_or_else(run::default_jobs),
+        timeout: args.timeout.or(config.timeout.value),
+        ..RunOptions::default()
+    };
+
+    run::verify(
+        &registry,
+        &args.selection(config)?,
+        &source,
+        &answers,
+        &options,
+    )
+}
+
+fn bench(args: &BenchArgs, config: &Config) -> Result<(), String> {
+    let previous = args.compare.as_deref().map(Report::load).transpose()?;
+    let source = InputSource::Dir(inputs(&args.inputs, config));
+
+    let report = run::bench(
+        &registry(),
+        &args.selection(config)?,
+        &source,
+        args.iterations.unwrap_or(config.iterations.value),
+        args.timeout.or(config.timeout.value),
+        previous.as_ref(),
+        args.threshold,
+    )?;
+    if let Some(path) = &args.output {
+        report.save(path)?;
+    }
+
+    if !report.failures.is_empty() {
+        return Err(format!(
+            "{} days could not be benchmarked",
+            report.failures.len()
+        ));
+    }
+    match previous.map(|it| report.regressions(&it, args.threshold).len()) {
+        Some(count) if count > 0 => Err(format!("{} steps regressed", count)),
+        _ => Ok(()),
+    }
+}
+
+fn fetch(args: &FetchArgs, config: &Config) -> Result<(), String> {
+    let year = year(args.year, config)?;
+    let fetcher = Fetcher {
+        http: UreqHttp,
+        base_url: args.server.base_url.clone(),
+        session: args.server.session(config)?,
+        cache: inputs(&args.inputs, config),
+        rate_limit: Duration::from_secs(args.rate_limit),
+    };
+
+    for day in args.day.iter() {
+        let path = fetcher.fetch(year, day)?;
+        println!("Year {} - Day {:02}: {}", year, day, path.display());
+    }
+    Ok(())
+}
+
+fn submit(args: &SubmitArgs, config: &Config) -> Result<(), String> {
+    let year = year(args.year, config)?;
+    let registry = registry();
+    let day = registry
+        .get(year, args.day)
+        .ok_or_else(|| "no registered day matches the selection".to_string())?;
+    let input = InputSource::Dir(inputs(&args.inputs, config)).read(year, args.day)?;
+    let answer = day.solve(args.part, &day.parse(&input)?);
+    println!("Part{}: {}", args.part, answer);
+    let answer = answer
+        .submission()
+        .ok_or_else(|| "the answer cannot be submitted".to_string())?;
+
+    let submitter = Submitter {
+        http: UreqHttp,
+        base_url: args.server.base_url.clone(),
+        session: args.server.session(config)?,
+        history: args
+            .history
+            .clone()
+            .unwrap_or_else(|| config.answers.value.join("history.json")),
+    };
+    match submitter.submit(year, args.day, args.part, &answer)? {
+        Verdict::Correct => {
+            println!("{}", Verdict::Correct);
+            Ok(())
+        }
+        verdict => Err(verdict.to_string()),
+    }
+}
+
+fn new(args: &NewArgs, config: &Config) -> Result<(), String> {
+    let year = year(args.year, config)?;
+    let changes = run::scaffold(Path::new("."), year, args.day)?;
+    if changes.is_empty() {
+        println!("Year {} - Day {:02} already exists", year, args.day);
+    }
+    changes.iter().for_each(|change| println!("{}", change));
+    Ok(())
+}
+
+fn execute(cli: Cli) -> Result<(), String> {
+    let config = Config::load(cli.config.as_deref())?;
+    match cli.command {
+        Command::Run(args) => run(&args, &config),
+        Command::Verify(args) => verify(&args, &config),
+        Command::Bench(args) => bench(&args, &config),
+        Command::Fetch(args) => fetch(&args, &config),
+        Command::Submit(args) => submit(&args, &config),
+        Command::New(args) => new(&args, &config),
+        Command::Watch(args) => args.watch(&config)?.watch(),
+        Command::Config(ConfigCommand::Show) => {
+            print!("{}", config.show());
+            Ok(())
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let result = execute(Cli::parse());
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{}", error);
+            ExitCode::FAILURE
+        }
+    }
 }
@@ -0,0 +1,128 @@
+use std::fmt::Display;
+
+use crate::{ocr, solution::Unsolved};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Int(i128),
+    Text(String),
+    Grid(Vec<String>),
+    Unsolved,
+}
+impl Answer {
+    pub fn ocr(&self) -> Option<String> {
+        match self {
+            Answer::Grid(lines) => ocr::read(lines),
+            _ => None,
+        }
+    }
+    pub fn submission(&self) -> Option<String> {
+        match self {
+            Answer::Int(value) => Some(value.to_string()),
+            Answer::Text(text) if !text.trim().is_empty() && !text.contains('\n') => {
+                Some(text.trim().to_string())
+            }
+            Answer::Grid(_) => self.ocr(),
+            _ => None,
+        }
+    }
+    pub fn matches(&self, expected: &str) -> bool {
+        let expected = expected.trim();
+        self.to_string().trim() == expected || self.ocr().is_some_and(|it| it == expected)
+    }
+}
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Int(value) => write!(f, "{}", value),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Grid(lines) => write!(f, "{}", lines.join("\n")),
+            Answer::Unsolved => write!(f, "{}", Unsolved),
+        }
+    }
+}
+impl PartialEq<str> for Answer {
+    fn eq(&self, other: &str) -> bool {
+        self.matches(other)
+    }
+}
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self.matches(other)
+    }
+}
+
+macro_rules! from_int {
+    ($($int:ty),+) => {
+        $(impl From<$int> for Answer {
+            fn from(value: $int) -> Self {
+                Answer::Int(value as i128)
+            }
+        })+
+    };
+}
+from_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, i128);
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        i128::try_from(value)
+            .map(Answer::Int)
+            .unwrap_or_else(|_| Answer::Text(value.to_string()))
+    }
+}
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+impl From<Unsolved> for Answer {
+    fn from(_: Unsolved) -> Self {
+        Answer::Unsolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Answer;
+
+    #[test]
+    fn integers_of_any_width() {
+        assert_eq!(Answer::from(42u8), Answer::from(42i64));
+        assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+        assert_eq!(Answer::from(-3i32).to_string(), "-3");
+    }
+
+    #[test]
+    fn compare_with_stored_answers() {
+        assert_eq!(Answer::from(17020usize), "17020\n");
+        assert_eq!(Answer::from("2=-1=0"), " 2=-1=0");
+        assert!(Answer::from(1) != "2");
+        assert!(Answer::Unsolved != "1");
+    }
+
+    #[test]
+    fn grid_answers() {
+        let grid = Answer::Grid(
+            ["####.", "#....", "###..", "#....", "#....", "####."]
+                .map(|it| it.to_string())
+                .to_vec(),
+        );
+
+        assert_eq!(grid.to_string(), "####.\n#....\n###..\n#....\n#....\n####.");
+        assert_eq!(grid.submission(), Some("E".to_string()));
+        assert_eq!(grid, "E");
+        assert_eq!(grid, "####.\n#....\n###..\n#....\n#....\n####.\n");
+    }
+
+    #[test]
+    fn submissions() {
+        assert_eq!(Answer::from(5).submission(), Some("5".to_string()));
+        assert_eq!(Answer::from("a\nb").submission(), None);
+        assert_eq!(Answer::Unsolved.submission(), None);
+    }
+}
@@ -0,0 +1,158 @@
+use std::{collections::HashMap, hash::Hash};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+impl Cycle {
+    pub fn extrapolate(&self, target: usize) -> usize {
+        if target < self.start {
+            target
+        } else {
+            self.start + (target - self.start) % self.length
+        }
+    }
+    pub fn repeats(&self, target: usize) -> usize {
+        target.saturating_sub(self.start) / self.length
+    }
+}
+
+pub fn floyd<S: PartialEq>(initial: S, next: impl Fn(&S) -> S) -> Cycle {
+    let mut tortoise = next(&initial);
+    let mut hare = next(&tortoise);
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&next(&hare));
+    }
+
+    let mut start = 0;
+    tortoise = initial;
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        start += 1;
+    }
+
+    let mut length = 1;
+    hare = next(&tortoise);
+    while tortoise != hare {
+        hare = next(&hare);
+        length += 1;
+    }
+    Cycle { start, length }
+}
+
+pub fn brent<S: Clone + PartialEq>(initial: S, next: impl Fn(&S) -> S) -> Cycle {
+    let (mut power, mut length) = (1, 1);
+    let mut tortoise = initial.clone();
+    let mut hare = next(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = next(&hare);
+        length += 1;
+    }
+
+    let mut start = 0;
+    hare = (0..length).fold(initial.clone(), |state, _| next(&state));
+    tortoise = initial;
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        start += 1;
+    }
+    Cycle { start, length }
+}
+
+pub fn first_repeat<K: Eq + Hash>(keys: impl IntoIterator<Item = K>) -> Option<Cycle> {
+    let mut seen = HashMap::new();
+    for (idx, key) in keys.into_iter().enumerate() {
+        if let Some(&start) = seen.get(&key) {
+            return Some(Cycle {
+                start,
+                length: idx - start,
+            });
+        }
+        seen.insert(key, idx);
+    }
+    None
+}
+
+pub fn find_by_key<S, K: Eq + Hash>(
+    initial: S,
+    mut next: impl FnMut(&S) -> S,
+    mut key: impl FnMut(&S) -> K,
+) -> (Cycle, Vec<S>) {
+    let mut seen = HashMap::new();
+    let mut states = vec![initial];
+    loop {
+        let state = states.last().unwrap();
+        let state_key = key(state);
+        if let Some(&start) = seen.get(&state_key) {
+            states.pop();
+            let length = states.len() - start;
+            return (Cycle { start, length }, states);
+        }
+        seen.insert(state_key, states.len() - 1);
+        let state = next(state);
+        states.push(state);
+    }
+}
+
+pub fn find<S: Clone + Eq + Hash>(initial: S, next: impl FnMut(&S) -> S) -> (Cycle, Vec<S>) {
+    find_by_key(initial, next, S::clone)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{brent, find, find_by_key, first_repeat, floyd, Cycle};
+
+    fn next(&state: &u32) -> u32 {
+        if state == 4 {
+            2
+        } else {
+            state + 1
+        }
+    }
+
+    #[test]
+    fn detect_cycles() {
+        let expected = Cycle {
+            start: 2,
+            length: 3,
+        };
+
+        assert_eq!(floyd(0, next), expected);
+        assert_eq!(brent(0, next), expected);
+        assert_eq!(find(0, next), (expected, vec![0, 1, 2, 3, 4]));
+        assert_eq!(first_repeat([0, 1, 2, 3, 4, 2]), Some(expected));
+        assert_eq!(first_repeat([0, 1, 2]), None);
+        assert_eq!(
+            floyd(7, |&it| (it * it + 1) % 255),
+            brent(7, |&it| (it * it + 1) % 255)
+        );
+    }
+
+    #[test]
+    fn detect_cycles_by_key() {
+        let (cycle, states) =
+            find_by_key((0, 0), |&(idx, value)| (idx + 1, next(&value)), |it| it.1);
+
+        assert_eq!(cycle.start, 2);
+        assert_eq!(states.len(), 5);
+    }
+
+    #[test]
+    fn extrapolate_far_targets() {
+        let (cycle, states) = find(0, next);
+
+        assert_eq!(states[cycle.extrapolate(1)], 1);
+        assert_eq!(states[cycle.extrapolate(10)], 4);
+        assert_eq!(cycle.repeats(10), 2);
+        assert_eq!(cycle.repeats(1), 0);
+    }
+}
@@ -0,0 +1,416 @@
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+pub trait Number:
+    Copy + Ord + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// The distance between `self` and `other`, or `None` when it does not fit
+    /// in `Self`, e.g. `i32::MIN.checked_abs_diff(i32::MAX)`.
+    fn checked_abs_diff(self, other: Self) -> Option<Self>;
+
+    /// The distance between `self` and `other`.
+    ///
+    /// # Panics
+    ///
+    /// When the distance does not fit in `Self`, see [Number::checked_abs_diff].
+    fn abs_diff(self, other: Self) -> Self {
+        self.checked_abs_diff(other)
+            .expect("distance does not fit in the number type")
+    }
+}
+
+macro_rules! number {
+    ($($int:ty),+) => {
+        $(impl Number for $int {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn checked_abs_diff(self, other: Self) -> Option<Self> {
+                Self::try_from(<$int>::abs_diff(self, other)).ok()
+            }
+        })+
+    };
+}
+number!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+impl<T: Number> Point2<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+    pub fn manhattan(&self, other: &Self) -> T {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+    pub fn chebyshev(&self, other: &Self) -> T {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+    pub fn signum(&self) -> Self
+    where
+        T: Neg<Output = T>,
+    {
+        Self::new(signum(self.x), signum(self.y))
+    }
+    pub fn step(&self, dir: impl Into<Dir8>) -> Self
+    where
+        T: Neg<Output = T>,
+    {
+        *self + dir.into().offset()
+    }
+    pub fn neighbors4(&self) -> impl Iterator<Item = Self> + '_
+    where
+        T: Neg<Output = T>,
+    {
+        Dir4::ALL.into_iter().map(|dir| self.step(dir))
+    }
+    pub fn neighbors8(&self) -> impl Iterator<Item = Self> + '_
+    where
+        T: Neg<Output = T>,
+    {
+        Dir8::ALL.into_iter().map(|dir| self.step(dir))
+    }
+}
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self { x, y }
+    }
+}
+impl<T: Display> Display for Point2<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+impl<T: Number> Point3<T> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+    pub fn manhattan(&self, other: &Self) -> T {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+    pub fn chebyshev(&self, other: &Self) -> T {
+        self.x
+            .abs_diff(other.x)
+            .max(self.y.abs_diff(other.y))
+            .max(self.z.abs_diff(other.z))
+    }
+    pub fn neighbors6(&self) -> impl Iterator<Item = Self> + '_
+    where
+        T: Neg<Output = T>,
+    {
+        let (zero, one) = (T::ZERO, T::ONE);
+        [
+            Self::new(-one, zero, zero),
+            Self::new(one, zero, zero),
+            Self::new(zero, -one, zero),
+            Self::new(zero, one, zero),
+            Self::new(zero, zero, -one),
+            Self::new(zero, zero, one),
+        ]
+        .into_iter()
+        .map(|offset| *self + offset)
+    }
+}
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Self { x, y, z }
+    }
+}
+impl<T: Display> Display for Point3<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+macro_rules! vector_ops {
+    ($point:ident, $($field:ident),+) => {
+        impl<T: Number> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self { $($field: self.$field + other.$field),+ }
+            }
+        }
+        impl<T: Number> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self { $($field: self.$field - other.$field),+ }
+            }
+        }
+        impl<T: Number> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, scalar: T) -> Self {
+                Self { $($field: self.$field * scalar),+ }
+            }
+        }
+        impl<T: Number> AddAssign for $point<T> {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+        impl<T: Number> SubAssign for $point<T> {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+        impl<T: Number + Neg<Output = T>> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($field: -self.$field),+ }
+            }
+        }
+    };
+}
+vector_ops!(Point2, x, y);
+vector_ops!(Point3, x, y, z);
+
+fn signum<T: Number + Neg<Output = T>>(value: T) -> T {
+    match value.cmp(&T::ZERO) {
+        std::cmp::Ordering::Less => -T::ONE,
+        std::cmp::Ordering::Equal => T::ZERO,
+        std::cmp::Ordering::Greater => T::ONE,
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+impl Dir4 {
+    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left];
+
+    fn rotate(self, quarters: usize) -> Self {
+        Self::ALL[(self as usize + quarters) % 4]
+    }
+    pub fn turn_right(self) -> Self {
+        self.rotate(1)
+    }
+    pub fn reverse(self) -> Self {
+        self.rotate(2)
+    }
+    pub fn turn_left(self) -> Self {
+        self.rotate(3)
+    }
+    pub fn offset<T: Number + Neg<Output = T>>(self) -> Point2<T> {
+        Dir8::from(self).offset()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+impl Dir8 {
+    pub const ALL: [Dir8; 8] = [
+        Dir8::Up,
+        Dir8::UpRight,
+        Dir8::Right,
+        Dir8::DownRight,
+        Dir8::Down,
+        Dir8::DownLeft,
+        Dir8::Left,
+        Dir8::UpLeft,
+    ];
+
+    fn rotate(self, eighths: usize) -> Self {
+        Self::ALL[(self as usize + eighths) % 8]
+    }
+    pub fn turn_right(self) -> Self {
+        self.rotate(1)
+    }
+    pub fn reverse(self) -> Self {
+        self.rotate(4)
+    }
+    pub fn turn_left(self) -> Self {
+        self.rotate(7)
+    }
+    pub fn offset<T: Number + Neg<Output = T>>(self) -> Point2<T> {
+        let (zero, one) = (T::ZERO, T::ONE);
+        let (x, y) = match self {
+            Dir8::Up => (zero, -one),
+            Dir8::UpRight => (one, -one),
+            Dir8::Right => (one, zero),
+            Dir8::DownRight => (one, one),
+            Dir8::Down => (zero, one),
+            Dir8::DownLeft => (-one, one),
+            Dir8::Left => (-one, zero),
+            Dir8::UpLeft => (-one, -one),
+        };
+        Point2::new(x, y)
+    }
+}
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        Dir8::ALL[dir as usize * 2]
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HexDir {
+    East,
+    NorthEast,
+    NorthWest,
+    West,
+    SouthWest,
+    SouthEast,
+}
+impl HexDir {
+    pub const ALL: [HexDir; 6] = [
+        HexDir::East,
+        HexDir::NorthEast,
+        HexDir::NorthWest,
+        HexDir::West,
+        HexDir::SouthWest,
+        HexDir::SouthEast,
+    ];
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 3) % 6]
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Hex {
+    pub q: i64,
+    pub r: i64,
+}
+impl Hex {
+    pub fn new(q: i64, r: i64) -> Self {
+        Self { q, r }
+    }
+    pub fn step(&self, dir: HexDir) -> Self {
+        let (q, r) = match dir {
+            HexDir::East => (1, 0),
+            HexDir::NorthEast => (1, -1),
+            HexDir::NorthWest => (0, -1),
+            HexDir::West => (-1, 0),
+            HexDir::SouthWest => (-1, 1),
+            HexDir::SouthEast => (0, 1),
+        };
+        Self::new(self.q + q, self.r + r)
+    }
+    pub fn neighbors(&self) -> impl Iterator<Item = Self> + '_ {
+        HexDir::ALL.into_iter().map(|dir| self.step(dir))
+    }
+    pub fn distance(&self, other: &Hex) -> i64 {
+        let (q, r) = (self.q - other.q, self.r - other.r);
+        (q.abs() + r.abs() + (q + r).abs()) / 2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod point {
+        use crate::geom::{Dir4, Dir8, Point2, Point3};
+
+        #[test]
+        fn arithmetic() {
+            let a = Point2::new(1, 2);
+            let b = Point2::new(3, -1);
+
+            assert_eq!(a + b, Point2::new(4, 1));
+            assert_eq!(a - b, Point2::new(-2, 3));
+            assert_eq!(a * 3, Point2::new(3, 6));
+            assert_eq!(-a, Point2::new(-1, -2));
+            assert_eq!(
+                Point3::new(1, 2, 3) + Point3::new(1, 1, 1),
+                Point3::new(2, 3, 4)
+            );
+        }
+
+        #[test]
+        fn distances() {
+            let a = Point2::new(1, 2);
+            let b = Point2::new(4, -2);
+
+            assert_eq!(a.manhattan(&b), 7);
+            assert_eq!(a.chebyshev(&b), 4);
+            assert_eq!(Point2::new(1u32, 5).manhattan(&Point2::new(3, 2)), 5);
+            assert_eq!(Point3::new(0, 0, 0).manhattan(&Point3::new(1, -2, 3)), 6);
+            assert_eq!(Point3::new(0, 0, 0).chebyshev(&Point3::new(1, -2, 3)), 3);
+        }
+
+        #[test]
+        fn abs_diff_overflow() {
+            use crate::geom::Number;
+
+            assert_eq!(Number::checked_abs_diff(-3i8, 4), Some(7));
+            assert_eq!(Number::checked_abs_diff(i32::MIN, i32::MAX), None);
+            assert_eq!(Number::checked_abs_diff(0u8, 255), Some(255));
+            let overflow = std::panic::catch_unwind(|| Number::abs_diff(i32::MIN, i32::MAX));
+            assert!(overflow.is_err());
+        }
+
+        #[test]
+        fn steps_and_neighbors() {
+            let origin = Point2::new(0, 0);
+
+            assert_eq!(origin.step(Dir4::Up), Point2::new(0, -1));
+            assert_eq!(origin.step(Dir8::DownLeft), Point2::new(-1, 1));
+            assert_eq!(Point2::new(-5, 3).signum(), Point2::new(-1, 1));
+            assert_eq!(origin.neighbors4().count(), 4);
+            assert_eq!(origin.neighbors8().count(), 8);
+            assert_eq!(Point3::new(0, 0, 0).neighbors6().count(), 6);
+        }
+    }
+
+    mod dir {
+        use crate::geom::{Dir4, Dir8};
+
+        #[test]
+        fn turns() {
+            assert_eq!(Dir4::Up.turn_right(), Dir4::Right);
+            assert_eq!(Dir4::Up.turn_left(), Dir4::Left);
+            assert_eq!(Dir4::Left.reverse(), Dir4::Right);
+            assert_eq!(Dir8::Up.turn_right(), Dir8::UpRight);
+            assert_eq!(Dir8::Up.turn_left(), Dir8::UpLeft);
+            assert_eq!(Dir8::UpRight.reverse(), Dir8::DownLeft);
+            assert_eq!(Dir8::from(Dir4::Left), Dir8::Left);
+        }
+    }
+
+    mod hex {
+        use crate::geom::{Hex, HexDir};
+
+        #[test]
+        fn distance() {
+            let origin = Hex::default();
+            let far = origin
+                .step(HexDir::East)
+                .step(HexDir::East)
+                .step(HexDir::NorthWest);
+
+            assert_eq!(far, Hex::new(2, -1));
+            assert_eq!(origin.distance(&far), 2);
+            assert_eq!(far.step(HexDir::West).step(HexDir::West.reverse()), far);
+            assert!(origin.neighbors().all(|it| it.distance(&origin) == 1));
+        }
+    }
+}
@@ -0,0 +1,183 @@
+use std::collections::HashMap;
+
+use crate::search;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Graph {
+    labels: Vec<String>,
+    ids: HashMap<String, usize>,
+    edges: Vec<Vec<(usize, usize)>>,
+}
+impl Graph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn intern(&mut self, label: &str) -> usize {
+        if let Some(&id) = self.ids.get(label) {
+            return id;
+        }
+        let id = self.labels.len();
+        self.labels.push(label.to_string());
+        self.ids.insert(label.to_string(), id);
+        self.edges.push(vec![]);
+        id
+    }
+    pub fn id(&self, label: &str) -> Option<usize> {
+        self.ids.get(label).copied()
+    }
+    pub fn label(&self, id: usize) -> &str {
+        &self.labels[id]
+    }
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+    pub fn add_edge(&mut self, from: &str, to: &str, weight: usize) {
+        let (from, to) = (self.intern(from), self.intern(to));
+        self.edges[from].retain(|&(it, _)| it != to);
+        self.edges[from].push((to, weight));
+    }
+    pub fn neighbors(&self, id: usize) -> &[(usize, usize)] {
+        &self.edges[id]
+    }
+    pub fn floyd_warshall(&self) -> Distances {
+        let mut distances = Distances::new(self.len());
+        (0..self.len()).for_each(|id| {
+            distances.set(id, id, 0);
+            self.edges[id]
+                .iter()
+                .for_each(|&(to, weight)| distances.relax(id, to, weight));
+        });
+
+        for k in 0..self.len() {
+            for i in 0..self.len() {
+                let Some(first) = distances.get(i, k) else {
+                    continue;
+                };
+                for j in 0..self.len() {
+                    if let Some(second) = distances.get(k, j) {
+                        distances.relax(i, j, first + second);
+                    }
+                }
+            }
+        }
+        distances
+    }
+    pub fn all_pairs_bfs(&self) -> Distances {
+        let mut distances = Distances::new(self.len());
+        (0..self.len()).for_each(|from| {
+            search::bfs_distances([from], |&id| self.edges[id].iter().map(|&(to, _)| to))
+                .into_iter()
+                .for_each(|(to, distance)| distances.set(from, to, distance));
+        });
+        distances
+    }
+    pub fn compress(&self, keep: impl Fn(usize) -> bool) -> Graph {
+        let distances = self.floyd_warshall();
+        let kept: Vec<usize> = (0..self.len()).filter(|&id| keep(id)).collect();
+
+        let mut graph = Graph::new();
+        kept.iter().for_each(|&id| {
+            graph.intern(self.label(id));
+        });
+        for &from in kept.iter() {
+            for &to in kept.iter().filter(|&&it| it != from) {
+                if let Some(distance) = distances.get(from, to) {
+                    graph.add_edge(self.label(from), self.label(to), distance);
+                }
+            }
+        }
+        graph
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Distances {
+    size: usize,
+    values: Vec<Option<usize>>,
+}
+impl Distances {
+    fn new(size: usize) -> Self {
+        Self {
+            size,
+            values: vec![None; size * size],
+        }
+    }
+    fn set(&mut self, from: usize, to: usize, distance: usize) {
+        self.values[from * self.size + to] = Some(distance);
+    }
+    fn relax(&mut self, from: usize, to: usize, distance: usize) {
+        let value = &mut self.values[from * self.size + to];
+        if value.is_none_or(|it| distance < it) {
+            *value = Some(distance);
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.size
+    }
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+    pub fn get(&self, from: usize, to: usize) -> Option<usize> {
+        self.values[from * self.size + to]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Graph;
+
+    fn graph() -> Graph {
+        let mut graph = Graph::new();
+        [("a", "b", 1), ("b", "c", 1), ("c", "d", 1), ("a", "d", 5)]
+            .into_iter()
+            .for_each(|(from, to, weight)| {
+                graph.add_edge(from, to, weight);
+                graph.add_edge(to, from, weight);
+            });
+        graph.intern("e");
+        graph
+    }
+
+    #[test]
+    fn intern_labels() {
+        let mut graph = graph();
+
+        assert_eq!(graph.len(), 5);
+        assert_eq!(graph.id("c"), Some(2));
+        assert_eq!(graph.intern("c"), 2);
+        assert_eq!(graph.label(4), "e");
+        assert_eq!(graph.id("z"), None);
+    }
+
+    #[test]
+    fn all_pairs_shortest_paths() {
+        let graph = graph();
+        let (a, d, e) = (0, 3, 4);
+
+        let weighted = graph.floyd_warshall();
+        let unweighted = graph.all_pairs_bfs();
+
+        assert_eq!(weighted.get(a, d), Some(3));
+        assert_eq!(weighted.get(d, a), Some(3));
+        assert_eq!(weighted.get(a, a), Some(0));
+        assert_eq!(weighted.get(a, e), None);
+        assert_eq!(unweighted.get(a, d), Some(1));
+        assert_eq!(unweighted.get(e, e), Some(0));
+        assert_eq!(unweighted.get(e, a), None);
+    }
+
+    #[test]
+    fn compress_to_interesting_nodes() {
+        let graph = graph();
+
+        let compressed = graph.compress(|id| ["a", "d"].contains(&graph.label(id)));
+        let distances = compressed.floyd_warshall();
+
+        assert_eq!(compressed.len(), 2);
+        assert_eq!(compressed.neighbors(0), [(1, 3)]);
+        assert_eq!(distances.get(1, 0), Some(3));
+    }
+}
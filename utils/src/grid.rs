@@ -0,0 +1,396 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use crate::parse::ParseError;
+
+pub type Pos = (usize, usize);
+
+const NEIGHBORS4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+const NEIGHBORS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+];
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Result<Self, String> {
+        if cells.len() != width * height {
+            return Err(format!(
+                "{} cells do not fit a {}x{} grid",
+                cells.len(),
+                width,
+                height
+            ));
+        }
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    pub fn contains(&self, x: isize, y: isize) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+    pub fn get(&self, (x, y): Pos) -> Option<&T> {
+        (x < self.width && y < self.height).then(|| &self.cells[y * self.width + x])
+    }
+    pub fn get_mut(&mut self, (x, y): Pos) -> Option<&mut T> {
+        (x < self.width && y < self.height).then(|| &mut self.cells[y * self.width + x])
+    }
+    pub fn offset(&self, (x, y): Pos, (dx, dy): (isize, isize)) -> Option<Pos> {
+        let (x, y) = (x as isize + dx, y as isize + dy);
+        self.contains(x, y).then_some((x as usize, y as usize))
+    }
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + '_ {
+        (0..self.height).flat_map(move |y| (0..self.width).map(move |x| (x, y)))
+    }
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Pos> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "row {} is outside the grid", y);
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} is outside the grid", x);
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBORS4
+            .iter()
+            .filter_map(move |&delta| self.offset(pos, delta))
+    }
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBORS8
+            .iter()
+            .filter_map(move |&delta| self.offset(pos, delta))
+    }
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+    pub fn view(&self, (x, y): Pos, width: usize, height: usize) -> Option<View<'_, T>> {
+        (x + width <= self.width && y + height <= self.height).then_some(View {
+            grid: self,
+            origin: (x, y),
+            width,
+            height,
+        })
+    }
+}
+impl<T: Clone> Grid<T> {
+    fn remap(&self, width: usize, height: usize, source: impl Fn(usize, usize) -> Pos) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| self[source(x, y)].clone())
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+    pub fn transpose(&self) -> Self {
+        self.remap(self.height, self.width, |x, y| (y, x))
+    }
+    pub fn rotate_clockwise(&self) -> Self {
+        self.remap(self.height, self.width, |x, y| (y, self.height - 1 - x))
+    }
+    pub fn rotate_counterclockwise(&self) -> Self {
+        self.remap(self.height, self.width, |x, y| (self.width - 1 - y, x))
+    }
+    pub fn flip_horizontal(&self) -> Self {
+        self.remap(self.width, self.height, |x, y| (self.width - 1 - x, y))
+    }
+    pub fn flip_vertical(&self) -> Self {
+        self.remap(self.width, self.height, |x, y| (x, self.height - 1 - y))
+    }
+}
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", pos))
+    }
+}
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", pos))
+    }
+}
+impl<T> FromStr for Grid<T>
+where
+    T: TryFrom<char>,
+    T::Error: Display,
+{
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut lines: Vec<&str> = input.lines().collect();
+        while lines.last().is_some_and(|it| it.is_empty()) {
+            lines.pop();
+        }
+        let width = lines.first().map(|it| it.chars().count()).unwrap_or(0);
+
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for (y, line) in lines.iter().enumerate() {
+            if line.is_empty() {
+                return Err(ParseError::new(1, line, "unexpected blank line").offset_lines(y));
+            }
+            if line.chars().count() != width {
+                return Err(ParseError::new(
+                    width.min(line.chars().count()) + 1,
+                    line,
+                    format!("expected {} cells", width),
+                )
+                .offset_lines(y));
+            }
+            for (x, char) in line.chars().enumerate() {
+                let cell = T::try_from(char).map_err(|error| {
+                    ParseError::new(x + 1, line, format!("invalid cell {:?}: {}", char, error))
+                        .offset_lines(y)
+                })?;
+                cells.push(cell);
+            }
+        }
+
+        Ok(Self {
+            width,
+            height: lines.len(),
+            cells,
+        })
+    }
+}
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_rows(f, self.rows())
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct View<'a, T> {
+    grid: &'a Grid<T>,
+    origin: Pos,
+    width: usize,
+    height: usize,
+}
+impl<'a, T> View<'a, T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    pub fn get(&self, (x, y): Pos) -> Option<&'a T> {
+        (x < self.width && y < self.height)
+            .then(|| &self.grid[(self.origin.0 + x, self.origin.1 + y)])
+    }
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> + '_ {
+        (0..self.height).map(|y| {
+            let row = self.grid.row(self.origin.1 + y);
+            &row[self.origin.0..self.origin.0 + self.width]
+        })
+    }
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.rows().flat_map(|it| it.iter().cloned()).collect(),
+        }
+    }
+}
+impl<T: Display> Display for View<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_rows(f, self.rows())
+    }
+}
+
+fn write_rows<'a, T: Display + 'a>(
+    f: &mut std::fmt::Formatter<'_>,
+    rows: impl Iterator<Item = &'a [T]>,
+) -> std::fmt::Result {
+    for (y, row) in rows.enumerate() {
+        if y > 0 {
+            writeln!(f)?;
+        }
+        for cell in row {
+            write!(f, "{}", cell)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Grid;
+
+    fn grid() -> Grid<char> {
+        "abc\ndef\n".parse().unwrap()
+    }
+
+    #[test]
+    fn parse_and_display() {
+        let grid = grid();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 1)], 'e');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.to_string(), "abc\ndef");
+        assert!("ab\nc".parse::<Grid<char>>().is_err());
+        assert_eq!("abc\ndef\n\n".parse::<Grid<char>>().unwrap().height(), 2);
+    }
+
+    #[test]
+    fn keep_lines_as_they_are() {
+        let grid: Grid<char> = ". #\n.. \n".parse().unwrap();
+        assert_eq!(grid.row(1), ['.', '.', ' ']);
+
+        let error = "ab\n\ncd".parse::<Grid<char>>().err().unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.message, "unexpected blank line");
+
+        let error = "ab\ncd \nef".parse::<Grid<char>>().err().unwrap();
+        assert_eq!((error.line, error.column), (2, 3));
+    }
+
+    #[test]
+    fn parse_cells() {
+        struct Digit(u32);
+        impl TryFrom<char> for Digit {
+            type Error = String;
+
+            fn try_from(value: char) -> Result<Self, Self::Error> {
+                value
+                    .to_digit(10)
+                    .map(Digit)
+                    .ok_or("not a digit".to_string())
+            }
+        }
+
+        let grid: Grid<Digit> = "12\n34".parse().unwrap();
+
+        assert_eq!(grid[(1, 1)].0, 4);
+        let error = "12\n3a".parse::<Grid<Digit>>().err().unwrap();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.message, "invalid cell 'a': not a digit");
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = grid();
+
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.column(2).collect::<String>(), "cf");
+        assert_eq!(
+            grid.columns()
+                .map(|it| it.collect::<String>())
+                .collect::<Vec<_>>(),
+            ["ad", "be", "cf"]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "row 2 is outside the grid")]
+    fn row_outside_the_grid() {
+        grid().row(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 is outside the grid")]
+    fn column_outside_the_grid() {
+        grid().column(3).count();
+    }
+
+    #[test]
+    fn neighbors() {
+        let grid = grid();
+
+        assert_eq!(
+            grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbors8((1, 0)).count(), 5);
+        assert_eq!(grid.neighbors8((1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn transformations() {
+        let grid = grid();
+
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed");
+        assert_eq!(grid.flip_vertical().to_string(), "def\nabc");
+        assert_eq!(
+            grid.rotate_clockwise()
+                .rotate_clockwise()
+                .rotate_clockwise()
+                .rotate_clockwise(),
+            grid
+        );
+    }
+
+    #[test]
+    fn sub_grid_views() {
+        let grid: Grid<char> = "abcd\nefgh\nijkl".parse().unwrap();
+
+        let view = grid.view((1, 1), 2, 2).unwrap();
+
+        assert_eq!(view.to_string(), "fg\njk");
+        assert_eq!(view.get((1, 0)), Some(&'g'));
+        assert_eq!(view.get((2, 0)), None);
+        assert_eq!(view.to_grid()[(0, 1)], 'j');
+        assert!(grid.view((3, 0), 2, 1).is_none());
+    }
+}
@@ -0,0 +1,144 @@
+use std::{
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    Dir(PathBuf),
+    File(PathBuf),
+    Stdin,
+}
+impl InputSource {
+    pub fn path(dir: &Path, year: u16, day: u8) -> PathBuf {
+        dir.join(year.to_string())
+            .join(format!("day{:02}.txt", day))
+    }
+    pub fn is_single(&self) -> bool {
+        !matches!(self, InputSource::Dir(_))
+    }
+    pub fn read(&self, year: u16, day: u8) -> Result<String, String> {
+        match self {
+            InputSource::Dir(dir) => read_file(&Self::path(dir, year, day)),
+            InputSource::File(path) => read_file(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|error| format!("cannot read input from stdin: {}", error))?;
+                Ok(input)
+            }
+        }
+    }
+}
+impl Default for InputSource {
+    fn default() -> Self {
+        InputSource::Dir(PathBuf::from("inputs"))
+    }
+}
+
+pub fn normalize(input: &str, trim_lines: bool) -> String {
+    let lines: Vec<&str> = input
+        .lines()
+        .map(|line| {
+            if trim_lines {
+                line.trim_end()
+            } else {
+                line.strip_suffix('\r').unwrap_or(line)
+            }
+        })
+        .collect();
+    let end = lines
+        .iter()
+        .rposition(|line| !line.trim().is_empty())
+        .map_or(0, |idx| idx + 1);
+
+    lines[..end]
+        .iter()
+        .map(|line| format!("{}\n", line))
+        .collect()
+}
+
+pub fn hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{:016x}", hash)
+}
+
+fn read_file(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path)
+        .map_err(|error| format!("cannot read input {}: {}", path.display(), error))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf};
+
+    use super::{hash, normalize, InputSource};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-input-{}-{}", name, std::process::id()));
+        fs::create_dir_all(dir.join("2022")).unwrap();
+        dir
+    }
+
+    #[test]
+    fn resolve_path_by_year_and_day() {
+        assert_eq!(
+            InputSource::path(&PathBuf::from("inputs"), 2022, 5),
+            PathBuf::from("inputs/2022/day05.txt")
+        );
+    }
+
+    #[test]
+    fn read_from_dir() {
+        let dir = temp_dir("dir");
+        fs::write(dir.join("2022/day01.txt"), "1\n2\n").unwrap();
+
+        let input = InputSource::Dir(dir.clone()).read(2022, 1);
+
+        assert_eq!(input, Ok("1\n2\n".to_string()));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn read_from_file() {
+        let dir = temp_dir("file");
+        let path = dir.join("example.txt");
+        fs::write(&path, "example").unwrap();
+
+        let input = InputSource::File(path).read(2022, 1);
+
+        assert_eq!(input, Ok("example".to_string()));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn missing_input() {
+        let dir = temp_dir("missing");
+
+        let error = InputSource::Dir(dir.clone()).read(2022, 3).unwrap_err();
+
+        assert!(error.contains("2022/day03.txt"));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn normalize_line_endings_and_whitespace() {
+        let input = "  [D] \r\n1 2\t\r\n\r\n\n  \n";
+
+        assert_eq!(normalize(input, true), "  [D]\n1 2\n");
+        assert_eq!(normalize(input, false), "  [D] \n1 2\t\n");
+        assert_eq!(normalize("a\n\nb", true), "a\n\nb\n");
+        assert_eq!(normalize("\n \n", true), "");
+    }
+
+    #[test]
+    fn stable_hash() {
+        assert_eq!(hash(""), "cbf29ce484222325");
+        assert_eq!(hash("a"), "af63dc4c8601ec8c");
+        assert_ne!(hash("1\n2\n"), hash("1\n3\n"));
+    }
+}
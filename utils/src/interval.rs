@@ -0,0 +1,338 @@
+use std::{
+    fmt::Display,
+    ops::{Range, RangeInclusive},
+};
+
+use crate::geom::Number;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+impl<T: Number> Interval<T> {
+    pub fn new(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+    pub fn inclusive(first: T, last: T) -> Self {
+        Self::new(first, last + T::ONE)
+    }
+    pub fn len(&self) -> T {
+        if self.is_empty() {
+            T::ZERO
+        } else {
+            self.end - self.start
+        }
+    }
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+    pub fn last(&self) -> T {
+        self.end - T::ONE
+    }
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+    pub fn contains_interval(&self, other: &Self) -> bool {
+        other.is_empty() || (self.start <= other.start && other.end <= self.end)
+    }
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let interval = Self::new(self.start.max(other.start), self.end.min(other.end));
+        (!interval.is_empty()).then_some(interval)
+    }
+    pub fn split_at(&self, at: T) -> (Option<Self>, Option<Self>) {
+        let below = Self::new(self.start, at.min(self.end));
+        let above = Self::new(at.max(self.start), self.end);
+        (
+            (!below.is_empty()).then_some(below),
+            (!above.is_empty()).then_some(above),
+        )
+    }
+    pub fn shift(&self, offset: T) -> Self {
+        Self::new(self.start + offset, self.end + offset)
+    }
+    fn unshift(&self, offset: T) -> Self {
+        Self::new(self.start - offset, self.end - offset)
+    }
+}
+impl<T: Display> Display for Interval<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}, {})", self.start, self.end)
+    }
+}
+impl<T> From<Range<T>> for Interval<T> {
+    fn from(range: Range<T>) -> Self {
+        Self {
+            start: range.start,
+            end: range.end,
+        }
+    }
+}
+impl<T: Number> From<RangeInclusive<T>> for Interval<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let (first, last) = range.into_inner();
+        Self::inclusive(first, last)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RangeSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+impl<T: Number> RangeSet<T> {
+    pub fn new() -> Self {
+        Self { intervals: vec![] }
+    }
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+    pub fn len(&self) -> T {
+        self.intervals
+            .iter()
+            .fold(T::ZERO, |total, it| total + it.len())
+    }
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|it| it.start)
+    }
+    pub fn max(&self) -> Option<T> {
+        self.intervals.last().map(|it| it.last())
+    }
+    pub fn contains(&self, value: T) -> bool {
+        self.intervals.iter().any(|it| it.contains(value))
+    }
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+        let mut merged = interval;
+        let mut intervals = Vec::with_capacity(self.intervals.len() + 1);
+        for &it in self.intervals.iter() {
+            if it.end < merged.start || merged.end < it.start {
+                intervals.push(it);
+            } else {
+                merged = Interval::new(it.start.min(merged.start), it.end.max(merged.end));
+            }
+        }
+        intervals.push(merged);
+        intervals.sort();
+        self.intervals = intervals;
+    }
+    pub fn union(&self, other: &Self) -> Self {
+        self.intervals
+            .iter()
+            .chain(other.intervals.iter())
+            .copied()
+            .collect()
+    }
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.intervals
+            .iter()
+            .flat_map(|it| other.intervals.iter().filter_map(|o| it.intersection(o)))
+            .collect()
+    }
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut intervals = vec![];
+        for &interval in self.intervals.iter() {
+            let mut start = interval.start;
+            for hole in other.intervals.iter().filter(|it| it.overlaps(&interval)) {
+                if start < hole.start {
+                    intervals.push(Interval::new(start, hole.start));
+                }
+                start = start.max(hole.end);
+            }
+            if start < interval.end {
+                intervals.push(Interval::new(start, interval.end));
+            }
+        }
+        Self { intervals }
+    }
+    pub fn split_at(&self, at: T) -> (Self, Self) {
+        let (below, above): (Vec<_>, Vec<_>) =
+            self.intervals.iter().map(|it| it.split_at(at)).unzip();
+        (
+            below.into_iter().flatten().collect(),
+            above.into_iter().flatten().collect(),
+        )
+    }
+    pub fn shift(&self, offset: T) -> Self {
+        Self {
+            intervals: self.intervals.iter().map(|it| it.shift(offset)).collect(),
+        }
+    }
+}
+impl<T: Number> From<Interval<T>> for RangeSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        [interval].into_iter().collect()
+    }
+}
+impl<T: Number> FromIterator<Interval<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        iter.into_iter().for_each(|it| set.insert(it));
+        set
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct OffsetMap<T> {
+    pieces: Vec<(Interval<T>, T)>,
+}
+impl<T: Number> OffsetMap<T> {
+    pub fn new() -> Self {
+        Self { pieces: vec![] }
+    }
+    pub fn pieces(&self) -> &[(Interval<T>, T)] {
+        &self.pieces
+    }
+    pub fn domain(&self) -> RangeSet<T> {
+        self.pieces.iter().map(|&(interval, _)| interval).collect()
+    }
+    pub fn insert(&mut self, interval: Interval<T>, offset: T) {
+        let replaced = RangeSet::from(interval);
+        let mut pieces: Vec<(Interval<T>, T)> = self
+            .pieces
+            .iter()
+            .flat_map(|&(it, it_offset)| {
+                RangeSet::from(it)
+                    .difference(&replaced)
+                    .intervals
+                    .into_iter()
+                    .map(move |it| (it, it_offset))
+            })
+            .collect();
+        if !interval.is_empty() && offset != T::ZERO {
+            pieces.push((interval, offset));
+        }
+        pieces.sort();
+        self.pieces = pieces;
+    }
+    pub fn get(&self, value: T) -> T {
+        self.pieces
+            .iter()
+            .find(|(interval, _)| interval.contains(value))
+            .map_or(value, |&(_, offset)| value + offset)
+    }
+    pub fn apply(&self, set: &RangeSet<T>) -> RangeSet<T> {
+        let mapped = self.pieces.iter().flat_map(|&(interval, offset)| {
+            set.intersection(&interval.into())
+                .shift(offset)
+                .intervals
+                .into_iter()
+        });
+        let unmapped = set.difference(&self.domain());
+        mapped.chain(unmapped.intervals).collect()
+    }
+    pub fn compose(&self, then: &Self) -> Self {
+        let mut composed = Self::new();
+        for &(interval, offset) in self.pieces.iter() {
+            let image = RangeSet::from(interval.shift(offset));
+            for &(other, other_offset) in then.pieces.iter() {
+                for part in image.intersection(&other.into()).intervals {
+                    composed.insert(part.unshift(offset), offset + other_offset);
+                }
+            }
+            for part in image.difference(&then.domain()).intervals {
+                composed.insert(part.unshift(offset), offset);
+            }
+        }
+        let domain = self.domain();
+        for &(other, other_offset) in then.pieces.iter() {
+            for part in RangeSet::from(other).difference(&domain).intervals {
+                composed.insert(part, other_offset);
+            }
+        }
+        composed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Interval, OffsetMap, RangeSet};
+
+    fn set(intervals: &[(i64, i64)]) -> RangeSet<i64> {
+        intervals
+            .iter()
+            .map(|&(start, end)| Interval::new(start, end))
+            .collect()
+    }
+
+    #[test]
+    fn intervals() {
+        let interval = Interval::from(2..=5);
+
+        assert_eq!(interval, Interval::new(2, 6));
+        assert_eq!(interval.len(), 4);
+        assert!(interval.contains(5) && !interval.contains(6));
+        assert!(interval.contains_interval(&Interval::from(3..5)));
+        assert!(interval.overlaps(&Interval::from(5..9)));
+        assert!(!interval.overlaps(&Interval::from(6..9)));
+        assert_eq!(
+            interval.intersection(&Interval::from(4..9)),
+            Some(Interval::new(4, 6))
+        );
+        assert_eq!(
+            interval.split_at(4),
+            (Some(Interval::new(2, 4)), Some(Interval::new(4, 6)))
+        );
+        assert_eq!(interval.split_at(1), (None, Some(interval)));
+        assert_eq!(interval.to_string(), "[2, 6)");
+    }
+
+    #[test]
+    fn normalized_sets() {
+        let numbers = set(&[(5, 8), (0, 2), (2, 3), (7, 10), (20, 20)]);
+
+        assert_eq!(numbers.intervals(), set(&[(0, 3), (5, 10)]).intervals());
+        assert_eq!(numbers.len(), 8);
+        assert_eq!((numbers.min(), numbers.max()), (Some(0), Some(9)));
+        assert!(numbers.contains(9) && !numbers.contains(4));
+    }
+
+    #[test]
+    fn set_algebra() {
+        let first = set(&[(0, 10), (20, 30)]);
+        let second = set(&[(5, 25)]);
+
+        assert_eq!(first.union(&second), set(&[(0, 30)]));
+        assert_eq!(first.intersection(&second), set(&[(5, 10), (20, 25)]));
+        assert_eq!(first.difference(&second), set(&[(0, 5), (25, 30)]));
+        assert_eq!(second.difference(&first), set(&[(10, 20)]));
+        assert_eq!(
+            first.split_at(22),
+            (set(&[(0, 10), (20, 22)]), set(&[(22, 30)]))
+        );
+    }
+
+    #[test]
+    fn offset_maps() {
+        let mut first = OffsetMap::new();
+        first.insert(Interval::new(0, 10), 100);
+        let mut second = OffsetMap::new();
+        second.insert(Interval::new(105, 200), -100);
+        second.insert(Interval::new(0, 3), 1);
+
+        assert_eq!(first.get(3), 103);
+        assert_eq!(first.get(10), 10);
+        assert_eq!(first.apply(&set(&[(8, 12)])), set(&[(10, 12), (108, 110)]));
+
+        let composed = first.compose(&second);
+        for value in -5..210 {
+            assert_eq!(
+                composed.get(value),
+                second.get(first.get(value)),
+                "{}",
+                value
+            );
+        }
+        assert_eq!(
+            composed.apply(&set(&[(0, 20)])),
+            second.apply(&first.apply(&set(&[(0, 20)])))
+        );
+    }
+}
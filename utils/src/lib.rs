@@ -1,9 +1,46 @@
-use regex::Regex;
+pub mod answer;
+pub mod cycle;
+pub mod geom;
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod math;
+pub mod ocr;
+pub mod parse;
+pub mod registry;
+pub mod search;
+pub mod solution;
+
+pub use grid::Grid;
+pub use parse::ParseError;
 
 #[macro_export]
-macro_rules! read_input {
-    () => {
-        include_str!("./input").to_string()
+macro_rules! examples {
+    ($solution:ty, $($part:ident: [$($file:literal => $expected:expr),+ $(,)?]),+ $(,)?) => {
+        #[cfg(test)]
+        mod examples {
+            use $crate::solution::Solution;
+
+            use super::*;
+
+            $(
+                #[test]
+                fn $part() {
+                    $(
+                        let input = $crate::input::normalize(include_str!($file), <$solution>::TRIM_LINES);
+                        let input = <$solution>::parse(&input)
+                            .unwrap_or_else(|error| panic!("{}: {}", $file, error));
+                        assert_eq!(
+                            $crate::answer::Answer::from(<$solution>::$part(&input)).to_string(),
+                            $expected.to_string(),
+                            "{}",
+                            $file
+                        );
+                    )+
+                }
+            )+
+        }
     };
 }
 
@@ -16,18 +53,15 @@ pub fn lines(input: String) -> Vec<String> {
 }
 
 pub fn replace(data: &str, regex: &str, text: &str) -> String {
-    let regex = Regex::new(regex).unwrap();
-    regex.replace_all(data, text).to_string()
+    parse::regex(regex).replace_all(data, text).to_string()
 }
 
 pub fn matches(data: &str, regex: &str) -> bool {
-    let regex = Regex::new(regex).unwrap();
-    regex.is_match(data)
+    parse::regex(regex).is_match(data)
 }
 
 pub fn extract(data: &str, regex: &str) -> Vec<String> {
-    Regex::new(regex)
-        .unwrap()
+    parse::regex(regex)
         .find_iter(data)
         .map(|digits| digits.as_str().to_owned())
         .collect()
@@ -0,0 +1,180 @@
+use std::ops::{Div, Rem};
+
+use crate::geom::Number;
+
+pub trait Integer: Number + Div<Output = Self> + Rem<Output = Self> {
+    fn checked_mul(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! integer {
+    ($($int:ty),+) => {
+        $(impl Integer for $int {
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                <$int>::checked_mul(self, other)
+            }
+        })+
+    };
+}
+integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// The greatest common divisor, or `None` when it does not fit in `T`, e.g.
+/// `checked_gcd(i32::MIN, 0)`.
+pub fn checked_gcd<T: Integer>(a: T, b: T) -> Option<T> {
+    let (mut a, mut b) = (a.checked_abs_diff(T::ZERO)?, b.checked_abs_diff(T::ZERO)?);
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    Some(a)
+}
+
+/// The greatest common divisor.
+///
+/// # Panics
+///
+/// When it does not fit in `T`, see [checked_gcd].
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    checked_gcd(a, b).expect("gcd overflow")
+}
+
+pub fn checked_lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    (a.checked_abs_diff(T::ZERO)? / checked_gcd(a, b)?).checked_mul(b.checked_abs_diff(T::ZERO)?)
+}
+
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    checked_lcm(a, b).expect("lcm overflow")
+}
+
+pub fn checked_lcm_all<T: Integer>(numbers: impl IntoIterator<Item = T>) -> Option<T> {
+    numbers
+        .into_iter()
+        .try_fold(T::ONE, |result, number| checked_lcm(result, number))
+}
+
+pub fn lcm_all<T: Integer>(numbers: impl IntoIterator<Item = T>) -> T {
+    checked_lcm_all(numbers).expect("lcm overflow")
+}
+
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a as i128, b as i128);
+    let (mut old_x, mut x) = (1i128, 0i128);
+    let (mut old_y, mut y) = (0i128, 1i128);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    if old_r < 0 {
+        (old_r, old_x, old_y) = (-old_r, -old_x, -old_y);
+    }
+    (old_r as i64, old_x as i64, old_y as i64)
+}
+
+/// `base` to the power `exp` modulo `modulus`, or `None` when `modulus` is not
+/// positive.
+pub fn mod_pow(base: i64, mut exp: u64, modulus: i64) -> Option<i64> {
+    if modulus <= 0 {
+        return None;
+    }
+    let modulus = modulus as i128;
+    let mut base = (base as i128).rem_euclid(modulus);
+    let mut result = 1 % modulus;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exp >>= 1;
+    }
+    Some(result as i64)
+}
+
+/// The inverse of `a` modulo `modulus`, or `None` when there is none or
+/// `modulus` is not positive.
+pub fn mod_inverse(a: i64, modulus: i64) -> Option<i64> {
+    if modulus <= 0 {
+        return None;
+    }
+    let (g, x, _) = extended_gcd(a, modulus);
+    (g == 1).then(|| (x as i128).rem_euclid(modulus as i128) as i64)
+}
+
+/// The `(residue, modulus)` satisfying all `(residue, modulus)` congruences, or
+/// `None` when they conflict, a modulus is not positive or the combined
+/// modulus overflows.
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    congruences.iter().try_fold(
+        (0i64, 1i64),
+        |(residue, modulus), &(other, other_modulus)| {
+            if other_modulus <= 0 {
+                return None;
+            }
+            let (g, x, _) = extended_gcd(modulus, other_modulus);
+            let difference = other as i128 - residue as i128;
+            if difference % g as i128 != 0 {
+                return None;
+            }
+            let step = (other_modulus / g) as i128;
+            let factor = (difference / g as i128 % step * x as i128).rem_euclid(step);
+            let combined = i64::try_from(modulus as i128 * step).ok()?;
+            let residue = (residue as i128 + modulus as i128 * factor).rem_euclid(combined as i128);
+            Some((residue as i64, combined))
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        checked_gcd, checked_lcm, checked_lcm_all, crt, extended_gcd, gcd, lcm, lcm_all,
+        mod_inverse, mod_pow,
+    };
+
+    #[test]
+    fn gcd_and_lcm() {
+        assert_eq!(gcd(12u64, 18), 6);
+        assert_eq!(gcd(-12i32, 18), 6);
+        assert_eq!(gcd(0usize, 5), 5);
+        assert_eq!(lcm(4i64, 6), 12);
+        assert_eq!(lcm(0u8, 6), 0);
+        assert_eq!(lcm_all([2u64, 3, 4, 5]), 60);
+        assert_eq!(checked_lcm(200u8, 3), None);
+        assert_eq!(checked_lcm(i32::MIN, 1), None);
+        assert_eq!(checked_lcm(1, i32::MIN), None);
+        assert_eq!(checked_gcd(-12i32, 18), Some(6));
+        assert_eq!(checked_gcd(i32::MIN, 0), None);
+        assert_eq!(checked_gcd(0, i64::MIN), None);
+        assert_eq!(checked_lcm_all([u64::MAX, 2]), None);
+    }
+
+    #[test]
+    fn modular_arithmetic() {
+        let (g, x, y) = extended_gcd(240, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+
+        assert_eq!(mod_pow(4, 13, 497), Some(445));
+        assert_eq!(mod_pow(-2, 3, 5), Some(2));
+        assert_eq!(mod_pow(i64::MAX, 2, i64::MAX - 1), Some(1));
+        assert_eq!(mod_pow(2, 3, 0), None);
+        assert_eq!(mod_pow(2, 3, -5), None);
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(2, 4), None);
+        assert_eq!(mod_inverse(3, 0), None);
+        assert_eq!(mod_inverse(3, -11), None);
+    }
+
+    #[test]
+    fn chinese_remainder() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(1, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(crt(&[]), Some((0, 1)));
+        assert_eq!(crt(&[(0, i64::MAX), (1, 2)]), None);
+        assert_eq!(crt(&[(2, 3), (0, 0)]), None);
+        assert_eq!(crt(&[(1, -4)]), None);
+    }
+}
@@ -0,0 +1,86 @@
+const HEIGHT: usize = 6;
+const WIDTH: usize = 4;
+
+const LETTERS: [(char, [&str; HEIGHT]); 18] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...", "#...", ".#.#", "..#.", "..#.", "..#."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+fn lit(char: char) -> bool {
+    !matches!(char, '.' | ' ')
+}
+
+pub fn read(lines: &[String]) -> Option<String> {
+    if lines.len() != HEIGHT {
+        return None;
+    }
+    let rows: Vec<Vec<bool>> = lines
+        .iter()
+        .map(|it| it.trim_end().chars().map(lit).collect())
+        .collect();
+    let width = rows.iter().map(|it| it.len()).max()?;
+    let pixel = |x: usize, y: usize| rows[y].get(x).copied().unwrap_or(false);
+
+    (0..width.div_ceil(WIDTH + 1))
+        .map(|letter| {
+            let start = letter * (WIDTH + 1);
+            LETTERS
+                .iter()
+                .find(|(_, shape)| {
+                    shape.iter().enumerate().all(|(y, row)| {
+                        row.chars()
+                            .enumerate()
+                            .all(|(x, char)| lit(char) == pixel(start + x, y))
+                    })
+                })
+                .map(|(char, _)| *char)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::read;
+
+    fn lines(input: &str) -> Vec<String> {
+        input.lines().map(|it| it.to_string()).collect()
+    }
+
+    #[test]
+    fn read_letters() {
+        let art = lines(
+            "###..#....####.####.####.#.....##..####.\n\
+             #..#.#....#.......#.#....#....#..#.#....\n\
+             #..#.#....###....#..###..#....#....###..\n\
+             ###..#....#.....#...#....#....#.##.#....\n\
+             #.#..#....#....#....#....#....#..#.#....\n\
+             #..#.####.####.####.#....####..###.####.",
+        );
+
+        assert_eq!(read(&art), Some("RLEZFLGE".to_string()));
+    }
+
+    #[test]
+    fn unknown_letters() {
+        let art = lines("#.#.\n.#.#\n#.#.\n.#.#\n#.#.\n.#.#");
+
+        assert_eq!(read(&art), None);
+        assert_eq!(read(&lines("####")), None);
+    }
+}
@@ -0,0 +1,293 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    str::FromStr,
+    sync::{Mutex, OnceLock},
+};
+
+use regex::Regex;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub year: Option<u16>,
+    pub day: Option<u8>,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+impl ParseError {
+    pub fn new(column: usize, text: &str, message: impl Display) -> Self {
+        Self {
+            year: None,
+            day: None,
+            line: 1,
+            column,
+            text: text.to_string(),
+            message: message.to_string(),
+        }
+    }
+    pub fn offset_lines(self, lines: usize) -> Self {
+        Self {
+            line: self.line + lines,
+            ..self
+        }
+    }
+    pub fn for_day(self, year: u16, day: u8) -> Self {
+        Self {
+            year: Some(year),
+            day: Some(day),
+            ..self
+        }
+    }
+}
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let (Some(year), Some(day)) = (self.year, self.day) {
+            write!(f, "{} day {:02}, ", year, day)?;
+        }
+        write!(
+            f,
+            "line {}, column {}: {} in {:?}",
+            self.line, self.column, self.message, self.text
+        )
+    }
+}
+impl From<ParseError> for String {
+    fn from(error: ParseError) -> Self {
+        error.to_string()
+    }
+}
+
+pub fn regex(pattern: &str) -> Regex {
+    static CACHE: OnceLock<Mutex<HashMap<String, Regex>>> = OnceLock::new();
+    let mut cache = CACHE.get_or_init(Default::default).lock().unwrap();
+    cache
+        .entry(pattern.to_string())
+        .or_insert_with(|| Regex::new(pattern).unwrap_or_else(|error| panic!("{}", error)))
+        .clone()
+}
+
+pub fn field<T>(text: &str, column: usize, value: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    value
+        .parse()
+        .map_err(|error| ParseError::new(column, text, format!("{:?}: {}", value, error)))
+}
+
+fn numbers<T>(text: &str, pattern: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    regex(pattern)
+        .find_iter(text)
+        .map(|it| field(text, it.start() + 1, it.as_str()))
+        .collect()
+}
+
+pub fn ints<T>(text: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    numbers(text, r"-?\d+")
+}
+
+pub fn uints<T>(text: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    numbers(text, r"\d+")
+}
+
+pub fn paragraphs(input: &str) -> Vec<&str> {
+    regex(r"\r?\n[ \t]*\r?\n")
+        .split(input)
+        .map(|it| it.trim_matches(['\r', '\n']))
+        .filter(|it| !it.trim().is_empty())
+        .collect()
+}
+
+pub fn columns(line: &str, width: usize) -> Vec<&str> {
+    let mut columns = vec![];
+    let mut rest = line;
+    while !rest.is_empty() {
+        let end = rest
+            .char_indices()
+            .nth(width)
+            .map_or(rest.len(), |(idx, _)| idx);
+        columns.push(&rest[..end]);
+        rest = &rest[end..];
+    }
+    columns
+}
+
+pub fn parse_lines<T>(
+    input: &str,
+    parse: impl Fn(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(idx, line)| parse(line).map_err(|error| error.offset_lines(idx)))
+        .collect()
+}
+
+pub fn parse_paragraphs<T>(
+    input: &str,
+    parse: impl Fn(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    paragraphs(input)
+        .into_iter()
+        .map(|paragraph| {
+            let offset = paragraph.as_ptr() as usize - input.as_ptr() as usize;
+            let line = input[..offset].matches('\n').count();
+            parse(paragraph).map_err(|error| error.offset_lines(line))
+        })
+        .collect()
+}
+
+pub fn scan<'a>(pattern: &str, text: &'a str) -> Result<Vec<(usize, &'a str)>, ParseError> {
+    let literals: Vec<&str> = pattern.split("{}").collect();
+    let expect = |position: usize, literal: &str| {
+        ParseError::new(position + 1, text, format!("expected {:?}", literal))
+    };
+
+    let mut position = 0;
+    if !text.starts_with(literals[0]) {
+        return Err(expect(position, literals[0]));
+    }
+    position += literals[0].len();
+
+    let mut fields = vec![];
+    for (idx, literal) in literals.iter().enumerate().skip(1) {
+        let rest = &text[position..];
+        let end = if idx == literals.len() - 1 {
+            rest.strip_suffix(literal)
+                .map(|it| it.len())
+                .ok_or_else(|| expect(text.len(), literal))?
+        } else if literal.is_empty() {
+            return Err(ParseError::new(
+                position + 1,
+                text,
+                format!("adjacent fields in {:?}", pattern),
+            ));
+        } else {
+            rest.find(literal)
+                .ok_or_else(|| expect(position, literal))?
+        };
+        fields.push((position + 1, &rest[..end]));
+        position += end + literal.len();
+    }
+    Ok(fields)
+}
+
+#[macro_export]
+macro_rules! scan {
+    ($text:expr, $pattern:literal, $($type:ty),+ $(,)?) => {{
+        let text: &str = $text;
+        $crate::parse::scan($pattern, text).and_then(|fields| {
+            let mut fields = fields.into_iter();
+            Ok(($({
+                let (column, value) = fields.next().ok_or_else(|| {
+                    $crate::parse::ParseError::new(text.len() + 1, text, "missing field")
+                })?;
+                $crate::parse::field::<$type>(text, column, value)?
+            },)+))
+        })
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        columns, ints, paragraphs, parse_lines, parse_paragraphs, regex, uints, ParseError,
+    };
+
+    #[test]
+    fn typed_numbers() {
+        assert_eq!(ints::<i64>("x=-3, y=12").unwrap(), [-3, 12]);
+        assert_eq!(uints::<u8>("x=-3, y=12").unwrap(), [3, 12]);
+        assert_eq!(
+            ints::<u8>("a 1 b 300").unwrap_err().to_string(),
+            "line 1, column 7: \"300\": number too large to fit in target type in \"a 1 b 300\""
+        );
+        assert!(regex(r"\d+").is_match("1"));
+    }
+
+    #[test]
+    fn split_paragraphs() {
+        assert_eq!(
+            paragraphs("a\nb\n\nc\r\n\r\nd\n  \ne\n"),
+            ["a\nb", "c", "d", "e"]
+        );
+        assert_eq!(paragraphs("\n  [D]\n1\n\nmove"), ["  [D]\n1", "move"]);
+
+        let error = parse_paragraphs("1\n2\n\n3\nx\n", |block| {
+            parse_lines(block, |line| {
+                line.parse::<u8>()
+                    .map_err(|error| ParseError::new(1, line, error))
+            })
+        })
+        .unwrap_err();
+        assert_eq!(error.line, 5);
+    }
+
+    #[test]
+    fn fixed_width_columns() {
+        assert_eq!(columns("    [D] [E]", 4), ["    ", "[D] ", "[E]"]);
+        assert_eq!(columns("", 4), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn scan_fields() {
+        let line = "Valve BB has flow rate=13; tunnels lead to valves CC, AA";
+
+        let (name, rate, _, tos) = scan!(
+            line,
+            "Valve {} has flow rate={}; {} to {}",
+            String,
+            usize,
+            String,
+            String
+        )
+        .unwrap();
+
+        assert_eq!((name.as_str(), rate), ("BB", 13));
+        assert_eq!(tos, "valves CC, AA");
+        assert_eq!(scan!("3-5", "{}-{}", i32, i32), Ok((3, 5)));
+    }
+
+    #[test]
+    fn errors_with_position() {
+        let error = |text| scan!(text, "move {} from {} to {}", u8, u8, u8).unwrap_err();
+
+        assert_eq!(error("move 1 to 2").column, 6);
+        assert_eq!(error("move 1 from 2 at 3").column, 13);
+        assert_eq!(error("move x from 2 to 3").column, 6);
+        assert_eq!(error("mov 1 from 2 to 3").message, "expected \"move \"");
+
+        let lines = parse_lines("3-5\n\n1-x\n", |line| scan!(line, "{}-{}", u8, u8));
+        assert_eq!(
+            lines,
+            Err(ParseError {
+                year: None,
+                day: None,
+                line: 3,
+                column: 3,
+                text: "1-x".to_string(),
+                message: "\"x\": invalid digit found in string".to_string(),
+            })
+        );
+        assert_eq!(
+            lines.unwrap_err().for_day(2022, 5).to_string(),
+            "2022 day 05, line 3, column 3: \"x\": invalid digit found in string in \"1-x\""
+        );
+    }
+}
@@ -0,0 +1,211 @@
+use std::{any::Any, fmt::Display, str::FromStr};
+
+use crate::{answer::Answer, input, parse::ParseError, solution::Solution};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+impl Part {
+    pub fn all() -> [Part; 2] {
+        [Part::One, Part::Two]
+    }
+}
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            other => Err(format!("invalid part {}, expected 1 or 2", other)),
+        }
+    }
+}
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+pub struct Parsed(Box<dyn Any>);
+
+#[derive(Clone, Debug)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    parse: fn(&str) -> Result<Parsed, ParseError>,
+    part1: fn(&Parsed) -> Answer,
+    part2: fn(&Parsed) -> Answer,
+}
+impl Day {
+    pub fn new<S: Solution>(year: u16, day: u8) -> Self
+    where
+        S::Input: 'static,
+    {
+        Self {
+            year,
+            day,
+            parse: parse::<S>,
+            part1: part1::<S>,
+            part2: part2::<S>,
+        }
+    }
+    pub fn parse(&self, input: &str) -> Result<Parsed, ParseError> {
+        (self.parse)(input).map_err(|error| error.for_day(self.year, self.day))
+    }
+    pub fn solve(&self, part: Part, input: &Parsed) -> Answer {
+        match part {
+            Part::One => (self.part1)(input),
+            Part::Two => (self.part2)(input),
+        }
+    }
+}
+
+fn parse<S: Solution>(input: &str) -> Result<Parsed, ParseError>
+where
+    S::Input: 'static,
+{
+    let input = input::normalize(input, S::TRIM_LINES);
+    Ok(Parsed(Box::new(S::parse(&input)?)))
+}
+
+fn part1<S: Solution>(input: &Parsed) -> Answer
+where
+    S::Input: 'static,
+{
+    S::part1(downcast::<S>(input)).into()
+}
+
+fn part2<S: Solution>(input: &Parsed) -> Answer
+where
+    S::Input: 'static,
+{
+    S::part2(downcast::<S>(input)).into()
+}
+
+fn downcast<S: Solution>(input: &Parsed) -> &S::Input
+where
+    S::Input: 'static,
+{
+    input
+        .0
+        .downcast_ref()
+        .expect("input parsed by a different solution")
+}
+
+#[derive(Debug, Default)]
+pub struct Registry {
+    days: Vec<Day>,
+}
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn add<S: Solution>(&mut self, year: u16, day: u8)
+    where
+        S::Input: 'static,
+    {
+        self.days.retain(|it| (it.year, it.day) != (year, day));
+        self.days.push(Day::new::<S>(year, day));
+        self.days.sort_by_key(|it| (it.year, it.day));
+    }
+    pub fn get(&self, year: u16, day: u8) -> Option<&Day> {
+        self.days.iter().find(|it| it.year == year && it.day == day)
+    }
+    pub fn days(&self) -> impl Iterator<Item = &Day> {
+        self.days.iter()
+    }
+    pub fn years(&self) -> Vec<u16> {
+        let mut years: Vec<u16> = self.days.iter().map(|it| it.year).collect();
+        years.dedup();
+        years
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        parse::{ints, ParseError},
+        solution::Solution,
+    };
+
+    use super::{Part, Registry};
+
+    struct Sum;
+    impl Solution for Sum {
+        type Input = Vec<u32>;
+        type Part1 = u32;
+        type Part2 = u32;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            ints(input)
+        }
+        fn part1(input: &Self::Input) -> Self::Part1 {
+            input.iter().sum()
+        }
+        fn part2(input: &Self::Input) -> Self::Part2 {
+            input.iter().product()
+        }
+    }
+
+    #[test]
+    fn parse_part() {
+        assert_eq!("1".parse::<Part>(), Ok(Part::One));
+        assert_eq!("2".parse::<Part>(), Ok(Part::Two));
+        assert!("3".parse::<Part>().is_err());
+    }
+
+    #[test]
+    fn keep_days_sorted() {
+        let mut registry = Registry::new();
+        registry.add::<Sum>(2023, 2);
+        registry.add::<Sum>(2022, 5);
+        registry.add::<Sum>(2023, 1);
+
+        let days: Vec<(u16, u8)> = registry.days().map(|it| (it.year, it.day)).collect();
+
+        assert_eq!(days, vec![(2022, 5), (2023, 1), (2023, 2)]);
+        assert_eq!(registry.years(), vec![2022, 2023]);
+    }
+
+    #[test]
+    fn replace_already_registered_day() {
+        let mut registry = Registry::new();
+        registry.add::<Sum>(2022, 1);
+        registry.add::<Sum>(2022, 1);
+
+        assert_eq!(registry.days().count(), 1);
+        assert!(registry.get(2022, 1).is_some());
+        assert!(registry.get(2022, 2).is_none());
+    }
+
+    #[test]
+    fn solve_parts_on_parsed_input() {
+        let mut registry = Registry::new();
+        registry.add::<Sum>(2022, 1);
+
+        let day = registry.get(2022, 1).unwrap();
+        let input = day.parse("2,3,4").unwrap();
+
+        assert_eq!(day.solve(Part::One, &input), "9");
+        assert_eq!(day.solve(Part::Two, &input), "24");
+    }
+
+    #[test]
+    fn report_parse_errors_with_day() {
+        let mut registry = Registry::new();
+        registry.add::<Sum>(2022, 1);
+
+        let error = registry.get(2022, 1).unwrap().parse("2,-3").err().unwrap();
+
+        assert_eq!(
+            (error.year, error.day, error.column),
+            (Some(2022), Some(1), 3)
+        );
+    }
+}
@@ -0,0 +1,281 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Found<S, C> {
+    pub cost: C,
+    pub path: Vec<S>,
+}
+
+struct Nodes<S, C> {
+    states: Vec<S>,
+    ids: HashMap<S, usize>,
+    parents: Vec<Option<usize>>,
+    costs: Vec<C>,
+}
+impl<S: Clone + Eq + Hash, C: Copy> Nodes<S, C> {
+    fn new() -> Self {
+        Self {
+            states: vec![],
+            ids: HashMap::new(),
+            parents: vec![],
+            costs: vec![],
+        }
+    }
+    fn id(&self, state: &S) -> Option<usize> {
+        self.ids.get(state).copied()
+    }
+    fn insert(&mut self, state: S, parent: Option<usize>, cost: C) -> usize {
+        let id = self.states.len();
+        self.ids.insert(state.clone(), id);
+        self.states.push(state);
+        self.parents.push(parent);
+        self.costs.push(cost);
+        id
+    }
+    fn found(&self, id: usize) -> Found<S, C> {
+        let mut path = vec![self.states[id].clone()];
+        let mut current = id;
+        while let Some(parent) = self.parents[current] {
+            path.push(self.states[parent].clone());
+            current = parent;
+        }
+        path.reverse();
+
+        Found {
+            cost: self.costs[id],
+            path,
+        }
+    }
+    fn distances(self) -> HashMap<S, C> {
+        self.states.into_iter().zip(self.costs).collect()
+    }
+}
+
+fn breadth_first<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut goal: impl FnMut(&S) -> bool,
+) -> (Nodes<S, usize>, Option<usize>)
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut nodes = Nodes::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if nodes.id(&start).is_none() {
+            queue.push_back(nodes.insert(start, None, 0));
+        }
+    }
+
+    while let Some(id) = queue.pop_front() {
+        if goal(&nodes.states[id]) {
+            return (nodes, Some(id));
+        }
+        let cost = nodes.costs[id] + 1;
+        for next in neighbors(&nodes.states[id]) {
+            if nodes.id(&next).is_none() {
+                queue.push_back(nodes.insert(next, Some(id), cost));
+            }
+        }
+    }
+    (nodes, None)
+}
+
+fn best_first<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut goal: impl FnMut(&S) -> bool,
+) -> (Nodes<S, C>, Option<usize>)
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut nodes = Nodes::new();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        if nodes.id(&start).is_none() {
+            let priority = heuristic(&start);
+            let id = nodes.insert(start, None, C::default());
+            heap.push(Reverse((priority, C::default(), id)));
+        }
+    }
+
+    while let Some(Reverse((_, cost, id))) = heap.pop() {
+        if cost > nodes.costs[id] {
+            continue;
+        }
+        if goal(&nodes.states[id]) {
+            return (nodes, Some(id));
+        }
+        for (next, step) in neighbors(&nodes.states[id]) {
+            let cost = cost + step;
+            let next_id = match nodes.id(&next) {
+                Some(next_id) if nodes.costs[next_id] <= cost => continue,
+                Some(next_id) => {
+                    nodes.costs[next_id] = cost;
+                    nodes.parents[next_id] = Some(id);
+                    next_id
+                }
+                None => nodes.insert(next.clone(), Some(id), cost),
+            };
+            heap.push(Reverse((cost + heuristic(&next), cost, next_id)));
+        }
+    }
+    (nodes, None)
+}
+
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    neighbors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let (nodes, found) = breadth_first(starts, neighbors, goal);
+    found.map(|id| nodes.found(id))
+}
+
+pub fn bfs_distances<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    neighbors: impl FnMut(&S) -> I,
+) -> HashMap<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    breadth_first(starts, neighbors, |_| false).0.distances()
+}
+
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    neighbors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, neighbors, |_| C::default(), goal)
+}
+
+pub fn dijkstra_distances<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    neighbors: impl FnMut(&S) -> I,
+) -> HashMap<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    best_first(starts, neighbors, |_| C::default(), |_| false)
+        .0
+        .distances()
+}
+
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    neighbors: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> C,
+    goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let (nodes, found) = best_first(starts, neighbors, heuristic, goal);
+    found.map(|id| nodes.found(id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{astar, bfs, bfs_distances, dijkstra, dijkstra_distances};
+
+    const MAZE: [&str; 5] = ["S.#....", ".##.##.", "...#...", ".#...#E", "...#..."];
+
+    fn open(x: i32, y: i32) -> bool {
+        MAZE.get(y as usize)
+            .and_then(|row| row.as_bytes().get(x as usize))
+            .is_some_and(|&it| it != b'#')
+    }
+
+    fn neighbors(&(x, y): &(i32, i32)) -> Vec<(i32, i32)> {
+        [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+            .into_iter()
+            .filter(|&(x, y)| open(x, y))
+            .collect()
+    }
+
+    #[test]
+    fn breadth_first_search() {
+        let found = bfs([(0, 0)], neighbors, |&it| it == (6, 3)).unwrap();
+
+        assert_eq!(found.cost, 11);
+        assert_eq!(found.path.len(), 12);
+        assert_eq!(found.path.first(), Some(&(0, 0)));
+        assert_eq!(found.path.last(), Some(&(6, 3)));
+        assert!(found
+            .path
+            .windows(2)
+            .all(|it| neighbors(&it[0]).contains(&it[1])));
+    }
+
+    #[test]
+    fn multiple_starts() {
+        let found = bfs([(0, 0), (6, 0)], neighbors, |&it| it == (6, 3)).unwrap();
+
+        assert_eq!(found.cost, 3);
+        assert_eq!(found.path[0], (6, 0));
+        assert!(bfs([(0, 0)], neighbors, |&it| it == (2, 0)).is_none());
+    }
+
+    #[test]
+    fn weighted_search() {
+        let weighted = |&(x, y): &(i32, i32)| {
+            neighbors(&(x, y))
+                .into_iter()
+                .map(|it| (it, if it.1 == 2 { 5 } else { 1 }))
+                .collect::<Vec<_>>()
+        };
+
+        let dijkstra = dijkstra([(0, 0)], weighted, |&it| it == (6, 3)).unwrap();
+        let astar = astar(
+            [(0, 0)],
+            weighted,
+            |&(x, y)| (6 - x).abs() + (3 - y).abs(),
+            |&it| it == (6, 3),
+        )
+        .unwrap();
+
+        assert_eq!(dijkstra.cost, 17);
+        assert_eq!(astar.cost, dijkstra.cost);
+    }
+
+    #[test]
+    fn all_distances() {
+        let distances = bfs_distances([(0, 0)], neighbors);
+        let weighted = dijkstra_distances([(0, 0)], |it| {
+            neighbors(it)
+                .into_iter()
+                .map(|it| (it, 2))
+                .collect::<Vec<_>>()
+        });
+
+        assert_eq!(distances.get(&(0, 0)), Some(&0));
+        assert_eq!(distances.get(&(6, 3)), Some(&11));
+        assert_eq!(distances.get(&(2, 0)), None);
+        assert_eq!(weighted.get(&(6, 3)), Some(&22));
+        assert_eq!(weighted.len(), distances.len());
+    }
+}
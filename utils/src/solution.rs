@@ -0,0 +1,23 @@
+use std::fmt::Display;
+
+use crate::{answer::Answer, parse::ParseError};
+
+pub trait Solution {
+    type Input;
+    type Part1: Into<Answer>;
+    type Part2: Into<Answer>;
+
+    const TRIM_LINES: bool = true;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Self::Part1;
+    fn part2(input: &Self::Input) -> Self::Part2;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Unsolved;
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "-")
+    }
+}
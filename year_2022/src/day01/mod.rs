@@ -1,36 +1,38 @@
-use utils::read_input;
+use utils::{
+    parse::{parse_paragraphs, uints},
+    solution::Solution,
+    ParseError,
+};
 
-pub fn run() {
-    part1(read_input!());
-    part2(read_input!());
-}
+pub struct Day01;
+impl Solution for Day01 {
+    type Input = Vec<u32>;
+    type Part1 = u32;
+    type Part2 = u32;
 
-fn part1(input: String) {
-    let resources: Vec<u32> = get_resources(input);
-    println!("Part1: {:?}", resources.iter().max().unwrap());
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        get_resources(input)
+    }
+    fn part1(resources: &Self::Input) -> Self::Part1 {
+        *resources.iter().max().unwrap()
+    }
+    fn part2(resources: &Self::Input) -> Self::Part2 {
+        resources.iter().take(3).sum()
+    }
 }
 
-fn part2(input: String) {
-    let resources: Vec<u32> = get_resources(input);
-    let top3: u32 = resources.iter().take(3).sum();
+fn get_resources(input: &str) -> Result<Vec<u32>, ParseError> {
+    let mut resources: Vec<u32> = parse_paragraphs(input, |block| Ok(uints(block)?.iter().sum()))?;
 
-    println!("Part2: {:?}", top3);
+    if resources.is_empty() {
+        return Err(ParseError::new(1, "", "expected at least one elf"));
+    }
+    resources.sort_by(|a, b| b.cmp(a));
+    Ok(resources)
 }
 
-fn get_resources(input: String) -> Vec<u32> {
-    let mut resources: Vec<u32> = input
-        .split("\n\n")
-        .collect::<Vec<&str>>()
-        .iter()
-        .map(|&inner_input| {
-            inner_input
-                .split('\n')
-                .filter(|it| !it.trim().is_empty())
-                .map(|it| it.parse::<u32>().unwrap())
-                .sum()
-        })
-        .collect();
-
-    resources.sort_by(|a, b| b.cmp(a));
-    resources
+utils::examples! {
+    Day01,
+    part1: ["example" => 24000],
+    part2: ["example" => 45000],
 }
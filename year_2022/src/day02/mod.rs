@@ -1,7 +1,7 @@
-use utils::read_input;
+use utils::{parse::parse_lines, scan, solution::Solution, ParseError};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
-enum Hand {
+pub enum Hand {
     Rock,
     Paper,
     Scissor,
@@ -26,73 +26,110 @@ impl Hand {
         }
     }
 }
-impl From<&str> for Hand {
-    fn from(input: &str) -> Self {
+impl TryFrom<&str> for Hand {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
         match input {
-            "A" => Self::Rock,
-            "B" => Self::Paper,
-            "C" => Self::Scissor,
-            _ => panic!("Invalid input"),
+            "A" => Ok(Self::Rock),
+            "B" => Ok(Self::Paper),
+            "C" => Ok(Self::Scissor),
+            _ => Err(ParseError::new(1, input, "expected A, B or C")),
         }
     }
 }
 impl From<Strategy> for Hand {
     fn from(input: Strategy) -> Self {
         match input {
-            Strategy::One(value) => match &value[..] {
-                "X" => Self::Rock,
-                "Y" => Self::Paper,
-                "Z" => Self::Scissor,
-                _ => panic!("Invalid input"),
+            Strategy::One(value) => match value {
+                Column::X => Self::Rock,
+                Column::Y => Self::Paper,
+                Column::Z => Self::Scissor,
             },
-            Strategy::Two(strategy, other_hand) => match (&strategy[..], other_hand) {
-                ("X", Hand::Rock) => Hand::Scissor,
-                ("X", Hand::Paper) => Hand::Rock,
-                ("X", Hand::Scissor) => Hand::Paper,
-                ("Y", hand) => hand,
-                ("Z", Hand::Rock) => Hand::Paper,
-                ("Z", Hand::Paper) => Hand::Scissor,
-                ("Z", Hand::Scissor) => Hand::Rock,
-                _ => panic!("Invalid input"),
+            Strategy::Two(strategy, other_hand) => match (strategy, other_hand) {
+                (Column::X, Hand::Rock) => Hand::Scissor,
+                (Column::X, Hand::Paper) => Hand::Rock,
+                (Column::X, Hand::Scissor) => Hand::Paper,
+                (Column::Y, hand) => hand,
+                (Column::Z, Hand::Rock) => Hand::Paper,
+                (Column::Z, Hand::Paper) => Hand::Scissor,
+                (Column::Z, Hand::Scissor) => Hand::Rock,
             },
         }
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Column {
+    X,
+    Y,
+    Z,
+}
+impl TryFrom<&str> for Column {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        match input {
+            "X" => Ok(Self::X),
+            "Y" => Ok(Self::Y),
+            "Z" => Ok(Self::Z),
+            _ => Err(ParseError::new(1, input, "expected X, Y or Z")),
+        }
+    }
+}
+
 enum Strategy {
-    One(String),
-    Two(String, Hand),
+    One(Column),
+    Two(Column, Hand),
 }
 
-pub fn run() {
-    println!("Part1: {:?}", run_part(strategy_1, read_input!()));
-    println!("Part2: {:?}", run_part(strategy_2, read_input!()));
+pub struct Day02;
+impl Solution for Day02 {
+    type Input = Vec<(Hand, Column)>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, round)
+    }
+    fn part1(rounds: &Self::Input) -> Self::Part1 {
+        run_part(strategy_1, rounds)
+    }
+    fn part2(rounds: &Self::Input) -> Self::Part2 {
+        run_part(strategy_2, rounds)
+    }
 }
 
-fn run_part(strategy: fn(&str) -> (Hand, Hand), input: String) -> u32 {
-    let data: Vec<(Hand, Hand)> = input
-        .split('\n')
-        .filter(|it| !it.is_empty())
-        .map(strategy)
-        .collect();
+fn round(line: &str) -> Result<(Hand, Column), ParseError> {
+    let (other_hand, column) = scan!(line, "{} {}", String, String)?;
+    let at = |error: ParseError, column: usize| ParseError { column, ..error };
+    Ok((
+        Hand::try_from(other_hand.as_str()).map_err(|error| at(error, 1))?,
+        Column::try_from(column.as_str()).map_err(|error| at(error, 3))?,
+    ))
+}
 
-    data.iter()
-        .map(|(other_hand, hand)| hand.points(other_hand))
+fn run_part(strategy: fn(&Hand, Column) -> (Hand, Hand), rounds: &[(Hand, Column)]) -> u32 {
+    rounds
+        .iter()
+        .map(|(other_hand, column)| strategy(other_hand, *column))
+        .map(|(other_hand, hand)| hand.points(&other_hand))
         .sum()
 }
 
-fn strategy_1(line: &str) -> (Hand, Hand) {
-    let hands = line.split(' ').take(2).collect::<Vec<&str>>();
-    let other_hand = hands[0].into();
-    let my_hand: Hand = Strategy::One(hands[1].to_owned()).into();
-
-    (other_hand, my_hand)
+fn strategy_1(other_hand: &Hand, column: Column) -> (Hand, Hand) {
+    (other_hand.clone(), Strategy::One(column).into())
 }
 
-fn strategy_2(line: &str) -> (Hand, Hand) {
-    let hands = line.split(' ').take(2).collect::<Vec<&str>>();
-    let other_hand: Hand = hands[0].into();
-    let my_hand: Hand = Strategy::Two(hands[1].to_owned(), other_hand.clone()).into();
+fn strategy_2(other_hand: &Hand, column: Column) -> (Hand, Hand) {
+    (
+        other_hand.clone(),
+        Strategy::Two(column, other_hand.clone()).into(),
+    )
+}
 
-    (other_hand, my_hand)
+utils::examples! {
+    Day02,
+    part1: ["example" => 15],
+    part2: ["example" => 12],
 }
@@ -1,20 +1,47 @@
-use utils::{lines, read_input};
+use utils::{parse::parse_lines, solution::Solution, ParseError};
 
-pub fn run() {
-    println!("Part1: {}", part1(read_input!()));
-    println!("Part2: {}", part2(read_input!()));
+pub struct Day03;
+impl Solution for Day03 {
+    type Input = Vec<String>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, rucksack)
+    }
+    fn part1(lines: &Self::Input) -> Self::Part1 {
+        priorities(lines)
+    }
+    fn part2(lines: &Self::Input) -> Self::Part2 {
+        badges(lines)
+    }
+}
+
+fn rucksack(line: &str) -> Result<String, ParseError> {
+    if let Some(idx) = line.find(|it: char| !it.is_ascii_alphabetic()) {
+        return Err(ParseError::new(idx + 1, line, "expected an item letter"));
+    }
+    if !line.len().is_multiple_of(2) {
+        return Err(ParseError::new(
+            line.len(),
+            line,
+            "compartments must have the same size",
+        ));
+    }
+    Ok(line.to_string())
 }
 
-fn part1(input: String) -> u32 {
-    lines(input)
-        .into_iter()
+fn priorities(lines: &[String]) -> u32 {
+    lines
+        .iter()
+        .cloned()
         .map(split_bag)
         .map(find_common_item)
         .sum::<u32>()
 }
 
-fn part2(input: String) -> u32 {
-    lines(input)
+fn badges(lines: &[String]) -> u32 {
+    lines
         .chunks(3)
         .map(|chunk| chunk.to_vec())
         .map(find_common_item)
@@ -44,9 +71,14 @@ fn find_common_item(data: Vec<String>) -> u32 {
 
     let value = match character {
         'A'..='Z' => (character as u8) - 65 + 27,
-        'a'..='z' => (character as u8) - 97 + 1,
-        _ => panic!("Invalid input"),
+        _ => (character as u8) - 97 + 1,
     };
 
     value.into()
 }
+
+utils::examples! {
+    Day03,
+    part1: ["example" => 157],
+    part2: ["example" => 70],
+}
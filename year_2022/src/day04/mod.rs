@@ -1,56 +1,44 @@
-use utils::{lines, read_input};
+use utils::{interval::Interval, parse::parse_lines, scan, solution::Solution, ParseError};
 
-#[derive(Clone)]
-struct Range {
-    min: u32,
-    max: u32,
-}
-impl Range {
-    fn contains(&self, other: &Range) -> bool {
-        self.min <= other.min && self.max >= other.max
+type Range = Interval<u32>;
+
+pub struct Day04;
+impl Solution for Day04 {
+    type Input = Vec<(Range, Range)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, to_ranges)
     }
-    fn intersect_left(&self, other: &Range) -> bool {
-        self.max >= other.min && self.min <= other.min
+    fn part1(ranges: &Self::Input) -> Self::Part1 {
+        count(ranges, filter_contains)
     }
-}
-impl From<String> for Range {
-    fn from(input: String) -> Self {
-        let values: Vec<u32> = input.split('-').map(|it| it.parse().unwrap()).collect();
-
-        Self {
-            min: values[0],
-            max: values[1],
-        }
+    fn part2(ranges: &Self::Input) -> Self::Part2 {
+        count(ranges, filter_intersect)
     }
 }
 
-pub fn run() {
-    println!("Part1: {}", count(filter_contains));
-    println!("Part2: {}", count(filter_intersect));
-}
-
-fn count(filter: impl FnMut(&(Range, Range)) -> bool) -> usize {
-    lines(read_input!())
-        .into_iter()
-        .map(to_ranges)
-        .filter(filter)
-        .count()
+fn count(ranges: &[(Range, Range)], filter: impl Fn(&(Range, Range)) -> bool) -> usize {
+    ranges.iter().filter(|it| filter(it)).count()
 }
 
 fn filter_contains((r1, r2): &(Range, Range)) -> bool {
-    r1.contains(r2) || r2.contains(r1)
+    r1.contains_interval(r2) || r2.contains_interval(r1)
 }
 
 fn filter_intersect((r1, r2): &(Range, Range)) -> bool {
-    r1.intersect_left(r2) || r2.intersect_left(r1)
+    r1.overlaps(r2)
 }
 
-fn to_ranges(input: String) -> (Range, Range) {
-    let inputs = input
-        .split(',')
-        .map(|it| it.to_owned())
-        .map(Into::into)
-        .collect::<Vec<Range>>();
+fn to_ranges(input: &str) -> Result<(Range, Range), ParseError> {
+    let (min1, max1, min2, max2) = scan!(input, "{}-{},{}-{}", u32, u32, u32, u32)?;
+
+    Ok((Range::inclusive(min1, max1), Range::inclusive(min2, max2)))
+}
 
-    (inputs[0].clone(), inputs[1].clone())
+utils::examples! {
+    Day04,
+    part1: ["example" => 2],
+    part2: ["example" => 4],
 }
@@ -3,16 +3,21 @@ use std::{
     fmt::Display,
 };
 
-use utils::{extract, lines, matches, read_input};
+use utils::{
+    parse::{columns, paragraphs, parse_lines},
+    scan,
+    solution::Solution,
+    ParseError,
+};
 
-#[derive(Debug)]
-struct Cargo {
+#[derive(Clone, Debug)]
+pub struct Cargo {
     crates: HashMap<usize, VecDeque<Crate>>,
 }
 impl Cargo {
     fn top_crates(&self) -> Vec<String> {
         (0..self.crates.len())
-            .map(|idx| self.crates.get(&idx).unwrap().get(0).unwrap().to_string())
+            .map(|idx| self.crates.get(&idx).unwrap().front().unwrap().to_string())
             .collect()
     }
     fn put_on_top(&mut self, idx: usize, krate: Crate) {
@@ -32,10 +37,8 @@ impl From<Vec<String>> for Cargo {
         };
 
         lines.iter().for_each(|line| {
-            line.chars()
-                .collect::<Vec<char>>()
-                .chunks(4)
-                .map(|it| it.to_vec().iter().collect::<String>())
+            columns(line, 4)
+                .into_iter()
                 .map(|it| it.trim().to_owned())
                 .enumerate()
                 .filter(|(_, c)| !c.is_empty())
@@ -48,7 +51,7 @@ impl From<Vec<String>> for Cargo {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 struct Crate(String);
 impl From<String> for Crate {
     fn from(string: String) -> Self {
@@ -89,7 +92,7 @@ impl Crane for Crane9001 {
 }
 
 #[derive(Debug)]
-struct Move {
+pub struct Move {
     quantity: usize,
     from: usize,
     to: usize,
@@ -99,54 +102,81 @@ impl Move {
         Self { quantity, from, to }
     }
 }
-impl From<String> for Move {
-    fn from(line: String) -> Self {
-        if !matches(&line, "move \\d+ from \\d+ to \\d+") {
-            panic!("invalid input")
-        }
-
-        let numbers: Vec<usize> = extract(&line, "\\d+")
-            .into_iter()
-            .filter_map(|digits| digits.as_str().parse().ok())
-            .collect();
+impl TryFrom<&str> for Move {
+    type Error = ParseError;
 
-        Self::new(numbers[0], numbers[1] - 1, numbers[2] - 1)
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        let (quantity, from, to) = scan!(line, "move {} from {} to {}", usize, usize, usize)?;
+        if from == 0 || to == 0 {
+            return Err(ParseError::new(1, line, "stacks are numbered from 1"));
+        }
+        Ok(Self::new(quantity, from - 1, to - 1))
     }
 }
 
-pub fn run() {
-    let (mut cargo, moves) = generate_data();
-    moves.iter().for_each(|movement| {
-        Crane9000::apply(movement, &mut cargo);
-    });
+pub struct Day05;
+impl Solution for Day05 {
+    type Input = (Cargo, Vec<Move>);
+    type Part1 = String;
+    type Part2 = String;
 
-    println!("Part1: {:?}", cargo.top_crates().join(""));
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generate_data(input)
+    }
+    fn part1((cargo, moves): &Self::Input) -> Self::Part1 {
+        let mut cargo = cargo.clone();
+        moves.iter().for_each(|movement| {
+            Crane9000::apply(movement, &mut cargo);
+        });
 
-    let (mut cargo, moves) = generate_data();
-    moves.iter().for_each(|movement| {
-        Crane9001::apply(movement, &mut cargo);
-    });
+        cargo.top_crates().join("")
+    }
+    fn part2((cargo, moves): &Self::Input) -> Self::Part2 {
+        let mut cargo = cargo.clone();
+        moves.iter().for_each(|movement| {
+            Crane9001::apply(movement, &mut cargo);
+        });
 
-    println!("Part2: {:?}", cargo.top_crates().join(""));
+        cargo.top_crates().join("")
+    }
 }
 
-fn generate_data() -> (Cargo, Vec<Move>) {
-    let input = lines(read_input!());
+fn generate_data(input: &str) -> Result<(Cargo, Vec<Move>), ParseError> {
+    let [drawing, moves] = paragraphs(input)[..] else {
+        return Err(ParseError::new(
+            1,
+            "",
+            "expected a drawing and a list of moves",
+        ));
+    };
 
-    let (mut config, moves): (Vec<String>, Vec<String>) =
-        input.into_iter().partition(|it| !it.starts_with("move"));
-    config.remove(config.len() - 1);
+    let mut config: Vec<String> = drawing.lines().map(|it| it.to_owned()).collect();
+    let offset = config.len() + 1;
+    let stacks = config.pop().map_or(0, |it| it.split_whitespace().count());
 
     let cargo: Cargo = config.into();
 
-    let moves: Vec<Move> = moves.into_iter().map(Into::into).collect();
+    let moves = parse_lines(moves, |line| {
+        let movement = Move::try_from(line)?;
+        if movement.from >= stacks || movement.to >= stacks {
+            return Err(ParseError::new(
+                1,
+                line,
+                format!("there are only {} stacks", stacks),
+            ));
+        }
+        Ok(movement)
+    })
+    .map_err(|error| error.offset_lines(offset))?;
 
-    (cargo, moves)
+    Ok((cargo, moves))
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::day05::{Crane, Crane9000, Crane9001};
+    use utils::solution::Solution;
+
+    use crate::day05::{Crane, Crane9000, Crane9001, Day05};
 
     use super::{Cargo, Move};
 
@@ -179,4 +209,19 @@ mod tests {
 
         assert_eq!(cargo.top_crates(), vec!["A", "C"]);
     }
+
+    #[test]
+    fn report_invalid_moves() {
+        let input = include_str!("example").replace("move 3", "move three");
+
+        let error = Day05::parse(&input).err().unwrap();
+
+        assert_eq!((error.line, error.column), (7, 6));
+    }
+}
+
+utils::examples! {
+    Day05,
+    part1: ["example" => "CMZ"],
+    part2: ["example" => "MCD"],
 }
@@ -1,12 +1,26 @@
 use std::{collections::HashSet, hash::Hash};
 
-use utils::read_input;
+use utils::{solution::Solution, ParseError};
 
-pub fn run() {
-    let input = read_input!();
+pub struct Day06;
+impl Solution for Day06 {
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    println!("Part1: {}", find_marker(&input, 4));
-    println!("Part2: {}", find_marker(&input, 14));
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let signal = input.trim_end();
+        if let Some(idx) = signal.find(|it: char| !it.is_ascii_lowercase()) {
+            return Err(ParseError::new(idx + 1, signal, "expected a lowercase letter"));
+        }
+        Ok(signal.to_owned())
+    }
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        find_marker(input, 4)
+    }
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        find_marker(input, 14)
+    }
 }
 
 fn find_marker(input: &str, distinct_chars: usize) -> usize {
@@ -23,3 +37,9 @@ fn find_marker(input: &str, distinct_chars: usize) -> usize {
 fn all_unique<T: Ord + Clone + Hash>(data: &[T]) -> bool {
     data.iter().cloned().collect::<HashSet<T>>().len() == data.len()
 }
+
+utils::examples! {
+    Day06,
+    part1: ["example" => 7],
+    part2: ["example" => 19],
+}
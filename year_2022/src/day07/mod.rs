@@ -4,43 +4,43 @@ use std::{
     rc::{Rc, Weak},
 };
 
-use utils::{lines, matches, read_input};
+use utils::{scan, solution::Solution, ParseError};
 
 type WeakFolder = Weak<RefCell<Folder>>;
 type RcFolder = Rc<RefCell<Folder>>;
 
 #[derive(Debug)]
 enum TerminalLine {
+    CdRoot,
     CdTo(String),
     CdBack,
     Ls,
     Dir(String),
     File(String, usize),
-    Nop,
 }
-impl From<&String> for TerminalLine {
-    fn from(line: &String) -> Self {
-        if matches(line, "\\$ cd \\w+") {
-            TerminalLine::CdTo(line.replace("$ cd ", ""))
-        } else if matches(line, "\\$ cd ..") {
-            TerminalLine::CdBack
-        } else if matches(line, "\\$ ls") {
-            TerminalLine::Ls
-        } else if matches(line, "dir \\w") {
-            TerminalLine::Dir(line.replace("dir ", ""))
-        } else if matches(line, "\\d+ \\w") {
-            let data: Vec<&str> = line.split(' ').collect();
-            let first = data[0].parse::<usize>().unwrap();
-            let second = data[1];
-            TerminalLine::File(second.to_owned(), first)
+impl TryFrom<&str> for TerminalLine {
+    type Error = ParseError;
+
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        if let Some(folder) = line.strip_prefix("$ cd ") {
+            Ok(match folder {
+                "/" => TerminalLine::CdRoot,
+                ".." => TerminalLine::CdBack,
+                name => TerminalLine::CdTo(name.to_owned()),
+            })
+        } else if line == "$ ls" {
+            Ok(TerminalLine::Ls)
+        } else if let Some(name) = line.strip_prefix("dir ") {
+            Ok(TerminalLine::Dir(name.to_owned()))
         } else {
-            TerminalLine::Nop
+            let (size, name) = scan!(line, "{} {}", usize, String)?;
+            Ok(TerminalLine::File(name, size))
         }
     }
 }
 
 #[derive(Debug)]
-struct Folder {
+pub struct Folder {
     parent: WeakFolder,
     name: String,
     size: usize,
@@ -59,9 +59,6 @@ impl Folder {
         self.folders
             .insert(folder.borrow().name.clone(), Rc::clone(&folder));
     }
-    fn get(&mut self, folder_name: String) -> &RcFolder {
-        self.folders.get(&folder_name).unwrap()
-    }
     fn increase_size(&mut self, size: usize) {
         self.size += size;
         if let Some(it) = Weak::upgrade(&self.parent) {
@@ -78,59 +75,65 @@ impl Folder {
     }
 }
 
-pub fn run() {
-    let data = lines(read_input!());
-    let mut iter = data.iter().map(TerminalLine::from);
-
-    iter.next();
+pub struct Day07;
+impl Solution for Day07 {
+    type Input = RcFolder;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let folder = Rc::new(RefCell::new(Folder::new("/".to_owned(), Weak::new())));
-    build_tree(&folder, &mut iter);
-
-    let mut folders = vec![];
-    folder
-        .borrow()
-        .walk(&mut folders, &|f| f.borrow().size < 100000);
-    println!(
-        "Part1: {:?}",
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generate_tree(input)
+    }
+    fn part1(folder: &Self::Input) -> Self::Part1 {
+        let mut folders = vec![];
+        folder
+            .borrow()
+            .walk(&mut folders, &|f| f.borrow().size < 100000);
         folders.iter().map(|it| it.borrow().size).sum::<usize>()
-    );
-
-    let mut folders = vec![];
-    let needed_space = 30000000 - (70000000 - folder.borrow().size);
-    folder
-        .borrow()
-        .walk(&mut folders, &|f| f.borrow().size > needed_space);
-    println!(
-        "Part2: {:?}",
+    }
+    fn part2(folder: &Self::Input) -> Self::Part2 {
+        let mut folders = vec![];
+        let needed_space = 30000000 - (70000000 - folder.borrow().size);
+        folder
+            .borrow()
+            .walk(&mut folders, &|f| f.borrow().size > needed_space);
         folders.iter().map(|it| it.borrow().size).min().unwrap()
-    );
+    }
 }
 
-fn build_tree(folder: &RcFolder, iter: &mut impl Iterator<Item = TerminalLine>) {
-    if let Some(line) = iter.next() {
-        match line {
-            TerminalLine::CdTo(subdir) => {
-                let subfolder = Rc::clone(folder.borrow_mut().get(subdir));
-                build_tree(&subfolder, iter);
+fn generate_tree(input: &str) -> Result<RcFolder, ParseError> {
+    let root = Rc::new(RefCell::new(Folder::new("/".to_owned(), Weak::new())));
+    let mut path = vec![Rc::clone(&root)];
+
+    for (idx, line) in input.lines().enumerate().filter(|(_, it)| !it.is_empty()) {
+        let error = |message| ParseError::new(1, line, message).offset_lines(idx);
+        let folder = Rc::clone(path.last().unwrap());
+        match TerminalLine::try_from(line).map_err(|it| it.offset_lines(idx))? {
+            TerminalLine::CdRoot => path.truncate(1),
+            TerminalLine::CdBack if path.len() == 1 => {
+                return Err(error("cannot leave the root folder"))
             }
             TerminalLine::CdBack => {
-                build_tree(&Weak::upgrade(&folder.borrow().parent).unwrap(), iter)
+                path.pop();
             }
-            TerminalLine::Dir(name) => {
-                let subdolder = Folder::new(name, Rc::downgrade(folder));
-                folder.borrow_mut().add(Rc::new(RefCell::new(subdolder)));
-
-                build_tree(folder, iter);
-            }
-            TerminalLine::File(_name, size) => {
-                folder.borrow_mut().increase_size(size);
-
-                build_tree(folder, iter);
+            TerminalLine::CdTo(name) => {
+                let subfolder = folder.borrow().folders.get(&name).cloned();
+                path.push(subfolder.ok_or_else(|| error("unknown folder"))?);
             }
-            _ => {
-                build_tree(folder, iter);
+            TerminalLine::Ls => {}
+            TerminalLine::Dir(name) => {
+                let subfolder = Folder::new(name, Rc::downgrade(&folder));
+                folder.borrow_mut().add(Rc::new(RefCell::new(subfolder)));
             }
+            TerminalLine::File(_name, size) => folder.borrow_mut().increase_size(size),
         }
     }
+
+    Ok(root)
+}
+
+utils::examples! {
+    Day07,
+    part1: ["example" => 95437],
+    part2: ["example" => 24933642],
 }
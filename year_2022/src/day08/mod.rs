@@ -1,14 +1,24 @@
-use utils::read_input;
+use utils::{parse::parse_lines, solution::Solution, ParseError};
 
 type Pos = (usize, usize);
 type Trees = Vec<usize>;
 type Matrix = Vec<Trees>;
 
-pub fn run() {
-    let matrix = create_matrix(read_input!());
+pub struct Day08;
+impl Solution for Day08 {
+    type Input = Matrix;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    println!("Part1: {}", walk(matrix.len() * 4 - 4, &matrix, updater1));
-    println!("Part2: {}", walk(None, &matrix, updater2).unwrap());
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        create_matrix(input)
+    }
+    fn part1(matrix: &Self::Input) -> Self::Part1 {
+        walk(matrix.len() * 4 - 4, matrix, updater1)
+    }
+    fn part2(matrix: &Self::Input) -> Self::Part2 {
+        walk(None, matrix, updater2).unwrap()
+    }
 }
 
 fn walk<T>(initial: T, matrix: &Matrix, updater: fn(&T, usize, Pos, &Matrix) -> T) -> T {
@@ -84,13 +94,34 @@ fn count_of_visible_trees(height: usize, other_trees: Trees) -> usize {
     }
 }
 
-fn create_matrix(data: String) -> Matrix {
-    data.split('\n')
-        .filter(|line| !line.is_empty())
-        .map(|line| {
-            line.chars()
-                .map(|c| c.to_string().parse::<usize>().unwrap())
-                .collect()
-        })
-        .collect()
+fn create_matrix(data: &str) -> Result<Matrix, ParseError> {
+    let width = data.lines().next().map_or(0, |it| it.len());
+    let matrix: Matrix = parse_lines(data, |line| {
+        if line.len() != width {
+            return Err(ParseError::new(1, line, "rows must have the same length"));
+        }
+        line.chars()
+            .enumerate()
+            .map(|(idx, c)| {
+                c.to_digit(10)
+                    .map(|it| it as usize)
+                    .ok_or_else(|| ParseError::new(idx + 1, line, "expected a tree height"))
+            })
+            .collect()
+    })?;
+
+    if matrix.len() != width || width < 3 {
+        return Err(ParseError::new(
+            1,
+            "",
+            "expected a square grid of at least 3 rows",
+        ));
+    }
+    Ok(matrix)
+}
+
+utils::examples! {
+    Day08,
+    part1: ["example" => 21],
+    part2: ["example" => 8],
 }
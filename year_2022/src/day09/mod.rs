@@ -1,94 +1,77 @@
 use std::{cell::RefCell, collections::HashSet, ops::Deref, rc::Rc};
 
-use utils::{lines, read_input};
+use utils::{
+    geom::{Dir4, Point2},
+    parse::parse_lines,
+    scan,
+    solution::Solution,
+    ParseError,
+};
 
 type RcPoint = Rc<RefCell<Point>>;
-type Pos = (i32, i32);
-
-#[derive(Debug, Clone)]
-enum Step {
-    Left,
-    Right,
-    Up,
-    Down,
-}
+type Pos = Point2<i32>;
 
 #[derive(Debug)]
-struct Steps {
-    steps: Vec<Step>,
+pub struct Steps {
+    steps: Vec<Dir4>,
 }
 impl Deref for Steps {
-    type Target = Vec<Step>;
+    type Target = Vec<Dir4>;
 
     fn deref(&self) -> &Self::Target {
         &self.steps
     }
 }
-impl From<Vec<String>> for Steps {
-    fn from(lines: Vec<String>) -> Self {
-        let steps = lines
-            .into_iter()
-            .flat_map(|it| {
-                let data: Vec<&str> = it.split(' ').collect();
-                let step = match data[0] {
-                    "L" => Step::Left,
-                    "R" => Step::Right,
-                    "U" => Step::Up,
-                    "D" => Step::Down,
-                    _ => panic!("invalid input"),
-                };
-                let count = data[1].parse::<i32>().expect("invalid input");
+impl TryFrom<&str> for Steps {
+    type Error = ParseError;
 
-                (0..count).map(|_| step.clone()).collect::<Vec<Step>>()
-            })
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let moves = parse_lines(input, |line| {
+            let (dir, count) = scan!(line, "{} {}", char, usize)?;
+            let step = match dir {
+                'L' => Dir4::Left,
+                'R' => Dir4::Right,
+                'U' => Dir4::Up,
+                'D' => Dir4::Down,
+                _ => return Err(ParseError::new(1, line, "expected L, R, U or D")),
+            };
+            Ok((step, count))
+        })?;
+        let steps = moves
+            .into_iter()
+            .flat_map(|(step, count)| (0..count).map(move |_| step))
             .collect();
-        Self { steps }
+        Ok(Self { steps })
     }
 }
 
 #[derive(Debug)]
 struct Point {
-    x: i32,
-    y: i32,
+    pos: Pos,
     tail: Option<RcPoint>,
     path: HashSet<Pos>,
 }
 impl Point {
     fn new(tail: Option<RcPoint>) -> Self {
         let mut point = Self {
-            x: 0,
-            y: 0,
+            pos: Pos::default(),
             tail,
             path: HashSet::new(),
         };
         point.update_path();
         point
     }
-    fn walk(&mut self, step: &Step) {
-        match step {
-            Step::Left => self.x -= 1,
-            Step::Right => self.x += 1,
-            Step::Up => self.y += 1,
-            Step::Down => self.y -= 1,
-        }
+    fn walk(&mut self, step: &Dir4) {
+        self.pos = self.pos.step(*step);
         self.update_path();
         self.drag_tail();
     }
     fn follow(&mut self, other: &Point) {
-        let x_diff = other.x - self.x;
-        let y_diff = other.y - self.y;
-
-        if x_diff.abs() <= 1 && y_diff.abs() <= 1 {
+        if self.pos.chebyshev(&other.pos) <= 1 {
             return;
         }
 
-        if x_diff.abs() >= 1 {
-            self.x += x_diff / x_diff.abs();
-        }
-
-        if y_diff.abs() >= 1 {
-            self.y += y_diff / y_diff.abs();
-        }
+        self.pos += (other.pos - self.pos).signum();
 
         self.update_path();
         self.drag_tail();
@@ -99,14 +82,25 @@ impl Point {
         }
     }
     fn update_path(&mut self) {
-        self.path.insert((self.x, self.y));
+        self.path.insert(self.pos);
     }
 }
 
-pub fn run() {
-    let steps: Steps = lines(read_input!()).into();
-    println!("Part1: {:?}", inner_run(1, &steps));
-    println!("Part2: {:?}", inner_run(9, &steps));
+pub struct Day09;
+impl Solution for Day09 {
+    type Input = Steps;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Steps::try_from(input)
+    }
+    fn part1(steps: &Self::Input) -> Self::Part1 {
+        inner_run(1, steps)
+    }
+    fn part2(steps: &Self::Input) -> Self::Part2 {
+        inner_run(9, steps)
+    }
 }
 
 fn inner_run(tails: usize, steps: &Steps) -> usize {
@@ -122,3 +116,12 @@ fn inner_run(tails: usize, steps: &Steps) -> usize {
     let borrowed_tail = tail.borrow_mut();
     borrowed_tail.path.len()
 }
+
+utils::examples! {
+    Day09,
+    part1: ["example" => 13],
+    part2: [
+        "example" => 1,
+        "example2" => 36,
+    ],
+}
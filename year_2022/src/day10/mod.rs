@@ -1,34 +1,33 @@
 use std::ops::Deref;
 
-use utils::{lines, read_input};
+use utils::{answer::Answer, parse::parse_lines, scan, solution::Solution, ParseError};
 
 #[derive(Debug)]
 enum MemoryOp {
     Add(i32),
     Noop,
 }
-impl From<String> for MemoryOp {
-    fn from(input: String) -> Self {
+impl TryFrom<&str> for MemoryOp {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
         if input == "noop" {
-            MemoryOp::Noop
-        } else if input.starts_with("addx") {
-            let (_, value) = input.split_once(' ').unwrap();
-            MemoryOp::Add(value.parse().unwrap())
+            Ok(MemoryOp::Noop)
         } else {
-            panic!("invalid input")
+            Ok(MemoryOp::Add(scan!(input, "addx {}", i32)?.0))
         }
     }
 }
 
 #[derive(Debug, Clone)]
-enum CpuOp {
+pub enum CpuOp {
     Add(i32),
     Loading,
     Noop,
 }
 
 #[derive(Debug, Clone)]
-struct CpuOps {
+pub struct CpuOps {
     ops: Vec<CpuOp>,
 }
 impl From<Vec<MemoryOp>> for CpuOps {
@@ -124,33 +123,43 @@ impl Crt {
     }
 }
 
-pub fn run() {
-    let ops: CpuOps = lines(read_input!())
-        .into_iter()
-        .map(MemoryOp::from)
-        .collect::<Vec<MemoryOp>>()
-        .into();
-
-    part1(ops.clone());
-    part2(ops);
-}
+pub struct Day10;
+impl Solution for Day10 {
+    type Input = CpuOps;
+    type Part1 = i32;
+    type Part2 = Answer;
 
-fn part1(ops: CpuOps) {
-    let register = Register::new(ops);
-
-    println!(
-        "Part1: {:?}",
-        register
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generate_ops(input)
+    }
+    fn part1(ops: &Self::Input) -> Self::Part1 {
+        Register::new(ops.clone())
             .skip(19)
             .step_by(40)
             .map(|(idx, value)| value * (idx as i32))
             .sum::<i32>()
-    );
+    }
+    fn part2(ops: &Self::Input) -> Self::Part2 {
+        let mut crt = Crt::new(ops.clone());
+        crt.run();
+        Answer::Grid(crt.lines)
+    }
+}
+
+fn generate_ops(input: &str) -> Result<CpuOps, ParseError> {
+    Ok(parse_lines(input, |line| MemoryOp::try_from(line))?.into())
 }
 
-fn part2(ops: CpuOps) {
-    let mut crt = Crt::new(ops);
-    crt.run();
-    println!("Part2:");
-    crt.lines.iter().for_each(|it| println!("{}", it));
+utils::examples! {
+    Day10,
+    part1: ["example" => 13140],
+    part2: ["example" => [
+        "##..##..##..##..##..##..##..##..##..##..",
+        "###...###...###...###...###...###...###.",
+        "####....####....####....####....####....",
+        "#####.....#####.....#####.....#####.....",
+        "######......######......######......####",
+        "#######.......#######.......#######.....",
+    ]
+    .join("\n")],
 }
@@ -1,6 +1,15 @@
-use std::collections::{HashMap, VecDeque};
-
-use utils::{extract, extract_one, read_input};
+use std::{
+    collections::{HashMap, VecDeque},
+    str::FromStr,
+};
+
+use utils::{
+    math,
+    parse::{parse_paragraphs, uints},
+    scan,
+    solution::Solution,
+    ParseError,
+};
 
 #[derive(Debug)]
 struct Throw {
@@ -8,19 +17,11 @@ struct Throw {
     item: Item,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 struct Item {
     value: usize,
 }
-impl From<&String> for Item {
-    fn from(input: &String) -> Self {
-        Self {
-            value: input.parse().unwrap(),
-        }
-    }
-}
-
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 enum Value {
     Old,
     Int(usize),
@@ -33,17 +34,21 @@ impl Value {
         }
     }
 }
-impl From<String> for Value {
-    fn from(input: String) -> Self {
-        if let Ok(val) = input.parse() {
-            Value::Int(val)
-        } else {
-            Value::Old
+impl FromStr for Value {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "old" => Ok(Value::Old),
+            _ => input
+                .parse()
+                .map(Value::Int)
+                .map_err(|_| "expected \"old\" or a number".to_string()),
         }
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 enum Op {
     Add,
     Subtract,
@@ -60,19 +65,21 @@ impl Op {
         }
     }
 }
-impl From<String> for Op {
-    fn from(input: String) -> Self {
-        match &input[..] {
-            "+" => Op::Add,
-            "-" => Op::Subtract,
-            "*" => Op::Multiply,
-            "/" => Op::Divide,
-            _ => panic!("invalid input"),
+impl FromStr for Op {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "+" => Ok(Op::Add),
+            "-" => Ok(Op::Subtract),
+            "*" => Ok(Op::Multiply),
+            "/" => Ok(Op::Divide),
+            _ => Err("expected one of + - * /".to_string()),
         }
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 struct Operation {
     left: Value,
     op: Op,
@@ -85,23 +92,8 @@ impl Operation {
         self.op.execute(left, right)
     }
 }
-impl From<String> for Operation {
-    fn from(input: String) -> Self {
-        let data = input
-            .split(' ')
-            .map(|it| it.to_owned())
-            .collect::<Vec<String>>();
-
-        Self {
-            left: data[0].clone().into(),
-            op: data[1].clone().into(),
-            right: data[2].clone().into(),
-        }
-    }
-}
-
-#[derive(Debug)]
-struct Monkey {
+#[derive(Clone, Debug)]
+pub struct Monkey {
     inspections: usize,
     items: VecDeque<Item>,
     operation: Operation,
@@ -132,53 +124,65 @@ impl Monkey {
         self.items.push_back(item);
     }
 }
-impl From<&str> for Monkey {
-    fn from(input: &str) -> Self {
-        let inputs = input.split('\n').collect::<Vec<&str>>();
-        let items = extract(inputs[1], "\\d+")
-            .iter()
-            .map(|it| it.into())
+impl TryFrom<&str> for Monkey {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let inputs = input.lines().map(|it| it.trim_end()).collect::<Vec<&str>>();
+        if inputs.len() != 6 {
+            return Err(ParseError::new(1, input, "expected 6 lines"));
+        }
+        let at = |line: usize| move |error: ParseError| error.offset_lines(line);
+
+        let items = uints::<usize>(inputs[1])
+            .map_err(at(1))?
+            .into_iter()
+            .map(|value| Item { value })
             .collect::<VecDeque<Item>>();
-        let operation: Operation =
-            extract_one(inputs[2], "(\\w+|\\d+) (\\+|-|\\*|/) (\\w+|\\d+)").into();
-        let condition: usize = extract_one(inputs[3], "\\d+").parse().unwrap();
-        let to_if_true: usize = extract_one(inputs[4], "\\d+").parse().unwrap();
-        let to_if_false: usize = extract_one(inputs[5], "\\d+").parse().unwrap();
-        Monkey {
+        let (left, op, right) =
+            scan!(inputs[2], "  Operation: new = {} {} {}", Value, Op, Value).map_err(at(2))?;
+        let operation = Operation { left, op, right };
+        let condition = scan!(inputs[3], "  Test: divisible by {}", usize).map_err(at(3))?;
+        let to_if_true =
+            scan!(inputs[4], "    If true: throw to monkey {}", usize).map_err(at(4))?;
+        let to_if_false =
+            scan!(inputs[5], "    If false: throw to monkey {}", usize).map_err(at(5))?;
+        Ok(Monkey {
             items,
             operation,
-            to_if_false,
-            to_if_true,
-            condition,
+            to_if_false: to_if_false.0,
+            to_if_true: to_if_true.0,
+            condition: condition.0,
             worry_level_divider: 3,
             bound: usize::MAX,
             inspections: 0,
-        }
+        })
     }
 }
 
-pub fn run() {
-    part1(&mut generate_monkeys());
-    part2(&mut generate_monkeys());
-}
+pub struct Day11;
+impl Solution for Day11 {
+    type Input = HashMap<usize, Monkey>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-fn part1(monkeys: &mut HashMap<usize, Monkey>) {
-    println!("Part1: {:?}", run_rounds(monkeys, 20));
-}
-
-fn part2(monkeys: &mut HashMap<usize, Monkey>) {
-    let bound = monkeys
-        .values()
-        .map(|it| it.condition)
-        .reduce(|a, b| a * b)
-        .unwrap();
-    (0..monkeys.len()).for_each(|monkey_idx| {
-        let monkey = monkeys.get_mut(&monkey_idx).unwrap();
-        monkey.bound = bound;
-        monkey.worry_level_divider = 1;
-    });
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generate_monkeys(input)
+    }
+    fn part1(monkeys: &Self::Input) -> Self::Part1 {
+        run_rounds(&mut monkeys.clone(), 20)
+    }
+    fn part2(monkeys: &Self::Input) -> Self::Part2 {
+        let monkeys = &mut monkeys.clone();
+        let bound = math::lcm_all(monkeys.values().map(|it| it.condition));
+        (0..monkeys.len()).for_each(|monkey_idx| {
+            let monkey = monkeys.get_mut(&monkey_idx).unwrap();
+            monkey.bound = bound;
+            monkey.worry_level_divider = 1;
+        });
 
-    println!("Part2: {:?}", run_rounds(monkeys, 10000));
+        run_rounds(monkeys, 10000)
+    }
 }
 
 fn run_rounds(monkeys: &mut HashMap<usize, Monkey>, rounds: usize) -> usize {
@@ -201,8 +205,51 @@ fn run_rounds(monkeys: &mut HashMap<usize, Monkey>, rounds: usize) -> usize {
     inspections[0] * inspections[1]
 }
 
-fn generate_monkeys() -> HashMap<usize, Monkey> {
-    let input = read_input!();
-    let input: Vec<&str> = input.split("\n\n").collect();
-    input.into_iter().map(Monkey::from).enumerate().collect()
+fn generate_monkeys(input: &str) -> Result<HashMap<usize, Monkey>, ParseError> {
+    let monkeys = parse_paragraphs(input, |it| {
+        let monkey = Monkey::try_from(it)?;
+        if monkey.condition == 0 {
+            let line = it.lines().nth(3).unwrap_or_default();
+            return Err(ParseError::new(1, line, "cannot test divisibility by 0").offset_lines(3));
+        }
+        Ok(monkey)
+    })?;
+    if monkeys.len() < 2 {
+        return Err(ParseError::new(1, "", "expected at least two monkeys"));
+    }
+    if let Some(target) = monkeys
+        .iter()
+        .flat_map(|it| [it.to_if_true, it.to_if_false])
+        .find(|&it| it >= monkeys.len())
+    {
+        return Err(ParseError::new(
+            1,
+            "",
+            format!("there is no monkey {}", target),
+        ));
+    }
+    Ok(monkeys.into_iter().enumerate().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use utils::solution::Solution;
+
+    use super::Day11;
+
+    #[test]
+    fn report_invalid_operation() {
+        let input = include_str!("example").replacen("old * 19", "old ^ 19", 1);
+
+        let error = Day11::parse(&input).err().unwrap();
+
+        assert_eq!((error.line, error.column), (3, 24));
+        assert_eq!(error.message, "\"^\": expected one of + - * /");
+    }
+}
+
+utils::examples! {
+    Day11,
+    part1: ["example" => 10605],
+    part2: ["example" => 2713310158_u64],
 }
@@ -1,6 +1,6 @@
-use std::collections::{BTreeSet, HashMap};
+use std::collections::HashMap;
 
-use utils::read_input;
+use utils::{parse::parse_lines, search, solution::Solution, ParseError};
 
 #[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
 struct Pos {
@@ -44,106 +44,96 @@ impl Palace {
 }
 
 #[derive(Debug)]
-struct Elevations {
+pub struct Elevations {
     palaces: HashMap<Pos, Palace>,
 }
-impl From<String> for Elevations {
-    fn from(input: String) -> Self {
-        Self {
-            palaces: input
-                .split('\n')
+impl TryFrom<&str> for Elevations {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let rows = parse_lines(input, |row| {
+            row.chars()
                 .enumerate()
-                .flat_map(|(r_idx, row)| {
-                    row.chars()
-                        .enumerate()
-                        .map(|(c_idx, palace)| {
-                            let pos = Pos::new(c_idx as i32, r_idx as i32);
-                            let palace = match palace {
-                                'S' => Palace::new(PalaceType::S, pos, 0),
-                                'E' => Palace::new(PalaceType::E, pos, 25),
-                                it => Palace::new(PalaceType::N, pos, (it as u8) - b'a'),
-                            };
-                            (pos, palace)
-                        })
-                        .collect::<Vec<(Pos, Palace)>>()
+                .map(|(c_idx, palace)| match palace {
+                    'S' => Ok((PalaceType::S, 0)),
+                    'E' => Ok((PalaceType::E, 25)),
+                    'a'..='z' => Ok((PalaceType::N, (palace as u8) - b'a')),
+                    _ => Err(ParseError::new(c_idx + 1, row, "expected a height")),
                 })
-                .collect(),
+                .collect::<Result<Vec<_>, _>>()
+        })?;
+        let palaces: HashMap<Pos, Palace> = rows
+            .into_iter()
+            .enumerate()
+            .flat_map(|(r_idx, row)| {
+                row.into_iter()
+                    .enumerate()
+                    .map(move |(c_idx, (type_, height))| {
+                        let pos = Pos::new(c_idx as i32, r_idx as i32);
+                        (pos, Palace::new(type_, pos, height))
+                    })
+            })
+            .collect();
+
+        for (type_, name) in [(PalaceType::S, "start"), (PalaceType::E, "end")] {
+            if palaces.values().filter(|it| it.type_ == type_).count() != 1 {
+                return Err(ParseError::new(
+                    1,
+                    "",
+                    format!("expected exactly one {}", name),
+                ));
+            }
         }
+        Ok(Self { palaces })
     }
 }
 impl Elevations {
-    fn run(
-        &mut self,
-        is_start: fn(&Palace) -> bool,
-        is_end: fn(&Palace) -> bool,
-        can_go_to: fn(&Palace, &Palace) -> bool,
-    ) -> usize {
-        let mut distances: HashMap<Pos, usize> = HashMap::new();
-        let mut queue: BTreeSet<(usize, &Palace)> = BTreeSet::new();
-
-        for palace in self.palaces.values() {
-            let distance = if is_start(palace) { 0 } else { usize::MAX };
-
-            queue.insert((distance, palace));
-            distances.insert(palace.pos, distance);
-        }
-
-        while !queue.is_empty() {
-            let (u_distance, palace) = *queue.iter().next().unwrap();
-            let u = palace.pos;
-
-            queue.remove(&(u_distance, palace));
-
-            if u_distance == usize::MAX {
-                continue;
-            }
-
-            let neighbours = u
-                .nearest()
-                .iter()
-                .filter_map(|pos| self.palaces.get(pos))
-                .filter(|&it| can_go_to(palace, it))
-                .collect::<Vec<&Palace>>();
+    fn run(&self, is_start: fn(&Palace) -> bool) -> usize {
+        let starts = self
+            .palaces
+            .values()
+            .filter(|it| is_start(it))
+            .map(|it| it.pos);
 
-            for palace in neighbours {
-                let v = palace.pos;
-                let v_distance = distances.get(&v).unwrap();
-                let alt = u_distance + 1;
+        search::bfs(
+            starts,
+            |pos| {
+                let palace = &self.palaces[pos];
+                pos.nearest()
+                    .into_iter()
+                    .filter(|it| {
+                        self.palaces
+                            .get(it)
+                            .is_some_and(|other| palace.can_go_to(other))
+                    })
+                    .collect::<Vec<Pos>>()
+            },
+            |pos| matches!(self.palaces[pos].type_, PalaceType::E),
+        )
+        .map(|it| it.cost)
+        .unwrap()
+    }
+}
 
-                if &alt < v_distance {
-                    queue.remove(&(*v_distance, palace));
-                    queue.insert((alt, palace));
-                    distances.insert(v, alt);
-                }
-            }
-        }
+pub struct Day12;
+impl Solution for Day12 {
+    type Input = Elevations;
+    type Part1 = usize;
+    type Part2 = usize;
 
-        *self
-            .palaces
-            .values()
-            .filter(|it| is_end(it))
-            .map(|it| distances.get(&it.pos).unwrap())
-            .min()
-            .unwrap()
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Elevations::try_from(input)
+    }
+    fn part1(elevations: &Self::Input) -> Self::Part1 {
+        elevations.run(|it| matches!(it.type_, PalaceType::S))
+    }
+    fn part2(elevations: &Self::Input) -> Self::Part2 {
+        elevations.run(|it| it.height == 0)
     }
 }
 
-pub fn run() {
-    let mut elevations: Elevations = read_input!().into();
-    println!(
-        "Part1: {}",
-        elevations.run(
-            |it| matches!(it.type_, PalaceType::S),
-            |it| matches!(it.type_, PalaceType::E),
-            |palace_from, palace_to| palace_from.can_go_to(palace_to)
-        )
-    );
-    println!(
-        "Part2: {}",
-        elevations.run(
-            |it| matches!(it.type_, PalaceType::E),
-            |it| it.height == 0,
-            |palace_from, palace_to| palace_to.can_go_to(palace_from)
-        )
-    );
+utils::examples! {
+    Day12,
+    part1: ["example" => 31],
+    part2: ["example" => 29],
 }
@@ -1,6 +1,10 @@
-use std::{cmp::Ordering, iter::Peekable};
+use std::{cmp::Ordering, iter::Peekable, str::CharIndices};
 
-use utils::{lines, read_input};
+use utils::{
+    parse::{field, parse_lines},
+    solution::Solution,
+    ParseError,
+};
 
 macro_rules! single {
     ($data:expr) => {{
@@ -18,58 +22,62 @@ macro_rules! multiple {
     };
 }
 
-fn parse_list(input: &str) -> List {
-    let mut input = input.chars().peekable();
-    if let Some(c) = input.peek() {
-        match c {
-            '0'..='9' => return build_single(&mut input),
-            '[' => {
-                input.next();
-                return build_multiple(&mut input);
-            }
-            _ => panic!("invalid input"),
+type Chars<'a> = Peekable<CharIndices<'a>>;
+
+fn parse_list(input: &str) -> Result<List, ParseError> {
+    let mut chars = input.char_indices().peekable();
+    let list = build(input, &mut chars)?;
+    match chars.next() {
+        Some((idx, _)) => Err(ParseError::new(idx + 1, input, "unexpected input")),
+        None => Ok(list),
+    }
+}
+
+fn build(input: &str, chars: &mut Chars) -> Result<List, ParseError> {
+    match chars.peek().copied() {
+        Some((_, '0'..='9')) => build_single(input, chars),
+        Some((_, '[')) => {
+            chars.next();
+            build_multiple(input, chars)
         }
+        Some((idx, _)) => Err(ParseError::new(
+            idx + 1,
+            input,
+            "expected a number or a list",
+        )),
+        None => Err(ParseError::new(input.len() + 1, input, "unexpected end")),
     }
-    panic!("no input");
 }
 
-fn build_multiple(input: &mut Peekable<impl Iterator<Item = char>>) -> List {
+fn build_multiple(input: &str, chars: &mut Chars) -> Result<List, ParseError> {
     let mut result: Vec<List> = vec![];
-    while let Some(c) = input.peek() {
+    while let Some(&(_, c)) = chars.peek() {
         match c {
-            '0'..='9' => result.push(build_single(input)),
-            '[' => {
-                input.next();
-                result.push(build_multiple(input))
-            }
             ']' => {
-                input.next();
-                return List::Multiple(result);
+                chars.next();
+                return Ok(List::Multiple(result));
             }
             ' ' | ',' => {
-                input.next();
+                chars.next();
             }
-            a => panic!("invalid input {}", a),
+            _ => result.push(build(input, chars)?),
         }
     }
-    panic!("no input");
+    Err(ParseError::new(input.len() + 1, input, "expected \"]\""))
 }
 
-fn build_single(input: &mut Peekable<impl Iterator<Item = char>>) -> List {
-    let mut number = String::new();
-    while let Some(c) = input.peek() {
-        if c.is_ascii_digit() {
-            number.push_str(&input.next().unwrap().to_string());
-        } else {
-            break;
-        }
+fn build_single(input: &str, chars: &mut Chars) -> Result<List, ParseError> {
+    let start = chars.peek().map_or(input.len(), |&(idx, _)| idx);
+    let mut end = start;
+    while let Some((idx, _)) = chars.next_if(|(_, c)| c.is_ascii_digit()) {
+        end = idx + 1;
     }
 
-    List::Single(number.parse().unwrap())
+    Ok(List::Single(field(input, start + 1, &input[start..end])?))
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
-enum List {
+pub enum List {
     Single(usize),
     Multiple(Vec<List>),
 }
@@ -107,31 +115,40 @@ impl List {
     }
 }
 
-pub fn run() {
-    let mut lines = lines(read_input!());
-    println!("Part1: {}", part1(&lines));
+pub struct Day13;
+impl Solution for Day13 {
+    type Input = Vec<List>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    lines.push("[[2]]".to_owned());
-    lines.push("[[6]]".to_owned());
-    println!("Part2: {}", part2(&lines));
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let packets = parse_lines(input, parse_list)?;
+        if !packets.len().is_multiple_of(2) {
+            return Err(ParseError::new(1, "", "packets must come in pairs"));
+        }
+        Ok(packets)
+    }
+    fn part1(packets: &Self::Input) -> Self::Part1 {
+        ordered_pairs(packets)
+    }
+    fn part2(packets: &Self::Input) -> Self::Part2 {
+        let mut packets = packets.clone();
+        packets.push(multiple!(multiple!(single!(2))));
+        packets.push(multiple!(multiple!(single!(6))));
+        decoder_key(packets)
+    }
 }
 
-fn part1(lines: &[String]) -> usize {
-    lines
+fn ordered_pairs(packets: &[List]) -> usize {
+    packets
         .chunks(2)
         .enumerate()
-        .filter(|(_, lines)| {
-            let left = parse_list(&lines[0]);
-            let right = parse_list(&lines[1]);
-            !matches!(left.cmp(&right), Ordering::Greater)
-        })
+        .filter(|(_, pair)| !matches!(pair[0].cmp(&pair[1]), Ordering::Greater))
         .map(|(idx, _)| idx + 1)
         .sum::<usize>()
 }
 
-fn part2(lines: &[String]) -> usize {
-    let mut sortable_list = lines.iter().map(|it| parse_list(it)).collect::<Vec<List>>();
-
+fn decoder_key(mut sortable_list: Vec<List>) -> usize {
     sortable_list.sort();
 
     sortable_list
@@ -152,31 +169,39 @@ mod tests {
         #[test]
         fn single_element() {
             let expected = single!(12);
-            assert_eq!(parse_list("12"), expected);
+            assert_eq!(parse_list("12"), Ok(expected));
         }
 
         #[test]
         fn single_element_list() {
             let expected = multiple!(single!(12));
-            assert_eq!(parse_list("[12]"), expected);
+            assert_eq!(parse_list("[12]"), Ok(expected));
         }
 
         #[test]
         fn multiple_list() {
             let expected = multiple!(single!(12), single!(21));
-            assert_eq!(parse_list("[12, 21]"), expected);
+            assert_eq!(parse_list("[12, 21]"), Ok(expected));
         }
 
         #[test]
         fn nested_list() {
             let expected = multiple!(multiple!(single!(12)));
-            assert_eq!(parse_list("[[12]]"), expected);
+            assert_eq!(parse_list("[[12]]"), Ok(expected));
         }
 
         #[test]
         fn mixed_list() {
             let expected = multiple!(multiple!(single!(12)), single!(1), multiple!());
-            assert_eq!(parse_list("[[12], 1, []]"), expected);
+            assert_eq!(parse_list("[[12], 1, []]"), Ok(expected));
+        }
+
+        #[test]
+        fn invalid_packets() {
+            assert_eq!(parse_list("[1, x]").unwrap_err().column, 5);
+            assert_eq!(parse_list("[[1]").unwrap_err().column, 5);
+            assert_eq!(parse_list("[1]]").unwrap_err().column, 4);
+            assert_eq!(parse_list("").unwrap_err().message, "unexpected end");
         }
     }
 
@@ -186,8 +211,8 @@ mod tests {
         use crate::day13::parse_list;
 
         fn compare(input1: &str, input2: &str) -> Ordering {
-            let left = parse_list(input1);
-            let right = parse_list(input2);
+            let left = parse_list(input1).unwrap();
+            let right = parse_list(input2).unwrap();
             left.cmp(&right)
         }
 
@@ -227,3 +252,9 @@ mod tests {
         }
     }
 }
+
+utils::examples! {
+    Day13,
+    part1: ["example" => 13],
+    part2: ["example" => 140],
+}
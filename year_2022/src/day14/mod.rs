@@ -1,8 +1,9 @@
-use std::{fmt::Display, isize};
+use std::fmt::Display;
 
-use utils::{lines, read_input};
+use utils::{parse::parse_lines, scan, solution::Solution, ParseError};
 
 type Pos = (isize, isize);
+type Trace = Vec<Pos>;
 
 #[derive(Debug, PartialEq, Eq)]
 struct Boundary {
@@ -20,20 +21,14 @@ impl Boundary {
         (pos.0 - self.min_x, pos.1 - self.min_y)
     }
 }
-impl From<Vec<String>> for Boundary {
-    fn from(lines: Vec<String>) -> Self {
+impl From<&[Trace]> for Boundary {
+    fn from(traces: &[Trace]) -> Self {
         let mut min_x = isize::MAX;
         let min_y = 0;
         let mut max_x = 500;
         let mut max_y = 0;
 
-        for it in lines
-            .iter()
-            .flat_map(|row| row.split(" -> ").collect::<Vec<&str>>())
-        {
-            let (x, y) = it.split_once(',').unwrap();
-            let (x, y) = (x.parse().unwrap(), y.parse().unwrap());
-
+        for &(x, y) in traces.iter().flatten() {
             min_x = if x < min_x { x } else { min_x };
             max_x = if x > max_x { x } else { max_x };
             max_y = if y > max_y { y } else { max_y };
@@ -69,17 +64,12 @@ impl Paths {
         ));
     }
 }
-impl From<Vec<String>> for Paths {
-    fn from(lines: Vec<String>) -> Self {
-        let boundary: Boundary = lines.clone().into();
-        let paths: Vec<Path> = lines
+impl From<&[Trace]> for Paths {
+    fn from(traces: &[Trace]) -> Self {
+        let boundary: Boundary = traces.into();
+        let paths: Vec<Path> = traces
             .iter()
-            .flat_map(|it| {
-                let a: Vec<String> = it.split(" -> ").map(|it| it.to_string()).collect();
-                a.windows(2)
-                    .map(|it| it.to_vec().into())
-                    .collect::<Vec<Path>>()
-            })
+            .flat_map(|it| it.windows(2).map(|it| Path::new(it[0], it[1])))
             .collect();
 
         Self { boundary, paths }
@@ -107,17 +97,6 @@ impl Path {
         }
     }
 }
-impl From<Vec<String>> for Path {
-    fn from(input: Vec<String>) -> Self {
-        let (start_x, start_y) = input[0].split_once(',').unwrap();
-        let (end_x, end_y) = input[1].split_once(',').unwrap();
-
-        let start = (start_x.parse().unwrap(), start_y.parse().unwrap());
-        let end = (end_x.parse().unwrap(), end_y.parse().unwrap());
-
-        Self::new(start, end)
-    }
-}
 impl Iterator for Path {
     type Item = Pos;
 
@@ -269,21 +248,55 @@ impl From<Paths> for Cave {
     }
 }
 
-pub fn run() {
-    let mut cave: Cave = create_cave(&read_input!());
-    println!("Part1: {}", cave.run());
+pub struct Day14;
+impl Solution for Day14 {
+    type Input = Vec<Trace>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let mut cave: Cave = create_cave_with_ground(&read_input!());
-    println!("Part2: {}", cave.run());
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, trace)
+    }
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        create_cave(input).run()
+    }
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        create_cave_with_ground(input).run()
+    }
 }
 
-fn create_cave(input: &str) -> Cave {
-    let paths: Paths = lines(input.to_string()).into();
+fn trace(line: &str) -> Result<Trace, ParseError> {
+    let mut column = 0;
+    let trace = line
+        .split(" -> ")
+        .map(|point| {
+            let (x, y) = scan!(point, "{},{}", isize, isize)
+                .map_err(|error| ParseError::new(column + error.column, line, error.message))?;
+            column += point.len() + 4;
+            Ok((x, y))
+        })
+        .collect::<Result<Trace, ParseError>>()?;
+
+    if trace
+        .windows(2)
+        .any(|it| it[0].0 != it[1].0 && it[0].1 != it[1].1)
+    {
+        return Err(ParseError::new(
+            1,
+            line,
+            "rock paths must be horizontal or vertical",
+        ));
+    }
+    Ok(trace)
+}
+
+fn create_cave(traces: &[Trace]) -> Cave {
+    let paths: Paths = traces.into();
     paths.into()
 }
 
-fn create_cave_with_ground(input: &str) -> Cave {
-    let mut paths: Paths = lines(input.to_string()).into();
+fn create_cave_with_ground(traces: &[Trace]) -> Cave {
+    let mut paths: Paths = traces.into();
     paths.add_ground();
     paths.into()
 }
@@ -291,15 +304,15 @@ fn create_cave_with_ground(input: &str) -> Cave {
 #[cfg(test)]
 mod tests {
     mod boundary {
-        use crate::day14::{Boundary, Pos};
+        use crate::day14::{trace, Boundary, Pos};
 
         #[test]
         fn parse_boundary() {
-            let boundary: Boundary = vec![
-                "498,4 -> 498,6 -> 496,6".to_string(),
-                "503,4 -> 502,4 -> 502,9 -> 494,9".to_string(),
-            ]
-            .into();
+            let traces = [
+                trace("498,4 -> 498,6 -> 496,6").unwrap(),
+                trace("503,4 -> 502,4 -> 502,9 -> 494,9").unwrap(),
+            ];
+            let boundary = Boundary::from(&traces[..]);
 
             assert_eq!(boundary, Boundary::new((494, 0), (503, 9)));
         }
@@ -334,14 +347,16 @@ mod tests {
     }
 
     mod path {
-        use crate::day14::{Path, Paths};
+        use crate::day14::{trace, Path, Paths};
 
         #[test]
         fn parse_path() {
             let expected_paths = vec![Path::new((0, 0), (0, 2))];
-            let paths: Paths = vec!["0,0 -> 0,2".to_string()].into();
+            let paths = Paths::from(&[trace("0,0 -> 0,2").unwrap()][..]);
 
             assert_eq!(paths.paths, expected_paths);
+            assert_eq!(trace("0,0 -> 0,x").unwrap_err().column, 10);
+            assert!(trace("0,0 -> 1,2").is_err());
         }
 
         #[test]
@@ -391,11 +406,19 @@ mod tests {
     }
 
     mod cave {
-        use crate::day14::{create_cave, create_cave_with_ground, Block, Boundary, Cave};
+        use utils::parse::parse_lines;
+
+        use crate::day14::{
+            create_cave, create_cave_with_ground, trace, Block, Boundary, Cave, Trace,
+        };
+
+        fn traces(input: &str) -> Vec<Trace> {
+            parse_lines(input, trace).unwrap()
+        }
 
         #[test]
         fn parse_cave() {
-            let cave: Cave = create_cave("498,4 -> 498,6");
+            let cave: Cave = create_cave(&traces("498,4 -> 498,6"));
 
             assert_eq!(cave.spawn, (500, -1));
             assert_eq!(cave.boundary, Boundary::new((498, 0), (500, 6)));
@@ -403,7 +426,7 @@ mod tests {
 
         #[test]
         fn find_at() {
-            let cave: Cave = create_cave("498,4 -> 498,6");
+            let cave: Cave = create_cave(&traces("498,4 -> 498,6"));
             assert_eq!(cave.at((498, 4)), Some(Block::Rock));
             assert_eq!(cave.at((498, 5)), Some(Block::Rock));
             assert_eq!(cave.at((498, 6)), Some(Block::Rock));
@@ -416,14 +439,14 @@ mod tests {
 
         #[test]
         fn set_at() {
-            let mut cave: Cave = create_cave("498,4 -> 498,6");
+            let mut cave: Cave = create_cave(&traces("498,4 -> 498,6"));
             cave.set((500, 1), Block::Sand);
             assert_eq!(cave.at((500, 1)), Some(Block::Sand));
         }
 
         #[test]
         fn move_sand_bottom() {
-            let mut cave: Cave = create_cave("500,2 -> 500,2");
+            let mut cave: Cave = create_cave(&traces("500,2 -> 500,2"));
             assert_eq!(cave.try_bottom(), Some(true));
             assert_eq!(cave.try_bottom(), Some(true));
             assert_eq!(cave.try_bottom(), Some(false));
@@ -433,7 +456,7 @@ mod tests {
 
         #[test]
         fn move_sand_bottom_left() {
-            let mut cave: Cave = create_cave("498,2 -> 502,2\n500,1 -> 500,2");
+            let mut cave: Cave = create_cave(&traces("498,2 -> 502,2\n500,1 -> 500,2"));
             cave.try_bottom();
             assert_eq!(cave.try_bottom_left(), Some(true));
             assert_eq!(cave.try_bottom_left(), Some(false));
@@ -443,7 +466,7 @@ mod tests {
 
         #[test]
         fn move_sand_bottom_right() {
-            let mut cave: Cave = create_cave("498,2 -> 502,2\n500,1 -> 500,1");
+            let mut cave: Cave = create_cave(&traces("498,2 -> 502,2\n500,1 -> 500,1"));
             cave.try_bottom();
             assert_eq!(cave.try_bottom_right(), Some(true));
             assert_eq!(cave.try_bottom_right(), Some(false));
@@ -453,7 +476,7 @@ mod tests {
 
         #[test]
         fn fall_outside() {
-            let mut cave: Cave = create_cave("498,1 -> 498,1");
+            let mut cave: Cave = create_cave(&traces("498,1 -> 498,1"));
             assert_eq!(cave.try_bottom(), Some(true));
             assert_eq!(cave.try_bottom(), Some(true));
             assert_eq!(cave.try_bottom(), None);
@@ -461,17 +484,24 @@ mod tests {
 
         #[test]
         fn part1() {
-            let mut cave: Cave =
-                create_cave("498,4 -> 498,6 -> 496,6\n503,4 -> 502,4 -> 502,9 -> 494,9\n");
+            let mut cave: Cave = create_cave(&traces(
+                "498,4 -> 498,6 -> 496,6\n503,4 -> 502,4 -> 502,9 -> 494,9\n",
+            ));
             assert_eq!(cave.run(), 24);
         }
 
         #[test]
         fn part2() {
-            let mut cave: Cave = create_cave_with_ground(
+            let mut cave: Cave = create_cave_with_ground(&traces(
                 "498,4 -> 498,6 -> 496,6\n503,4 -> 502,4 -> 502,9 -> 494,9\n",
-            );
+            ));
             assert_eq!(cave.run(), 93);
         }
     }
 }
+
+utils::examples! {
+    Day14,
+    part1: ["example" => 24],
+    part2: ["example" => 93],
+}
@@ -1,6 +1,12 @@
 use std::collections::HashSet;
 
-use utils::{extract, lines, read_input};
+use utils::{
+    interval::{Interval, RangeSet},
+    parse::parse_lines,
+    scan,
+    solution::Solution,
+    ParseError,
+};
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 struct Point(i32, i32);
@@ -9,15 +15,9 @@ impl Point {
         (self.0.abs_diff(other.0) + self.1.abs_diff(other.1)) as i32
     }
 }
-impl From<String> for Point {
-    fn from(input: String) -> Self {
-        let data = extract(&input, "-?\\d+");
-        Point(data[0].parse().unwrap(), data[1].parse().unwrap())
-    }
-}
 
 #[derive(Clone, Debug)]
-struct SensorAndBeacon {
+pub struct SensorAndBeacon {
     sensor: Point,
     beacon: Point,
     range: i32,
@@ -78,66 +78,86 @@ impl SensorAndBeacon {
             .collect()
     }
 }
-impl From<String> for SensorAndBeacon {
-    fn from(input: String) -> Self {
-        let data = extract(&input, "x=-?\\d+, y=-?\\d+");
-        Self::new(data[0].to_string().into(), data[1].to_string().into())
-    }
-}
+impl TryFrom<&str> for SensorAndBeacon {
+    type Error = ParseError;
 
-pub fn run() {
-    let sensors_and_beacons: Vec<SensorAndBeacon> = lines(read_input!())
-        .into_iter()
-        .map(SensorAndBeacon::from)
-        .collect();
-    part1(&sensors_and_beacons);
-    part2(&sensors_and_beacons);
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let (sensor_x, sensor_y, beacon_x, beacon_y) = scan!(
+            input,
+            "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+            i32,
+            i32,
+            i32,
+            i32
+        )?;
+        Ok(Self::new(
+            Point(sensor_x, sensor_y),
+            Point(beacon_x, beacon_y),
+        ))
+    }
 }
 
-fn part1(sensors_and_beacons: &[SensorAndBeacon]) {
-    let y = 2000000;
-    let mut min_x = i32::MAX;
-    let mut max_x = i32::MIN;
+pub struct Day15;
+impl Solution for Day15 {
+    type Input = Vec<SensorAndBeacon>;
+    type Part1 = usize;
+    type Part2 = u128;
 
-    sensors_and_beacons
-        .iter()
-        .flat_map(|sb| vec![sb.sensor.0 - sb.range, sb.sensor.0 + sb.range])
-        .for_each(|b| {
-            min_x = b.min(min_x);
-            max_x = b.max(max_x);
-        });
-
-    let count = (min_x..max_x)
-        .filter(|&x| {
-            let candidate = Point(x, y);
-            sensors_and_beacons
-                .iter()
-                .any(|sb| sb.reaches(&candidate) && candidate != sb.beacon)
-        })
-        .count();
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generate_sensors_and_beacons(input)
+    }
+    fn part1(sensors_and_beacons: &Self::Input) -> Self::Part1 {
+        covered_positions(sensors_and_beacons, 2000000)
+    }
+    fn part2(sensors_and_beacons: &Self::Input) -> Self::Part2 {
+        let point = sensors_and_beacons
+            .iter()
+            .cloned()
+            .flat_map(|first| {
+                sensors_and_beacons
+                    .iter()
+                    .skip(1)
+                    .flat_map(|second| first.candidates(second))
+                    .collect::<Vec<Point>>()
+            })
+            .find(|&it| sensors_and_beacons.iter().all(|sb| !sb.reaches(&it)))
+            .unwrap();
 
-    println!("Part1: {}", count);
+        (point.0 as u128) * 4000000 + (point.1 as u128)
+    }
 }
 
-fn part2(sensors_and_beacons: &[SensorAndBeacon]) {
-    let point = sensors_and_beacons
+fn covered_positions(sensors_and_beacons: &[SensorAndBeacon], y: i32) -> usize {
+    let covered: RangeSet<i32> = sensors_and_beacons
         .iter()
-        .cloned()
-        .flat_map(|first| {
-            sensors_and_beacons
-                .iter()
-                .skip(1)
-                .flat_map(|second| first.candidates(second))
-                .collect::<Vec<Point>>()
+        .filter_map(|sb| {
+            let width = sb.range - sb.sensor.1.abs_diff(y) as i32;
+            (width >= 0).then(|| Interval::inclusive(sb.sensor.0 - width, sb.sensor.0 + width))
         })
-        .find(|&it| sensors_and_beacons.iter().all(|sb| !sb.reaches(&it)))
-        .unwrap();
+        .collect();
+    let beacons: RangeSet<i32> = sensors_and_beacons
+        .iter()
+        .filter(|sb| sb.beacon.1 == y)
+        .map(|sb| Interval::from(sb.beacon.0..=sb.beacon.0))
+        .collect();
+
+    covered.difference(&beacons).len() as usize
+}
 
-    println!("Part2: {}", (point.0 as u128) * 4000000 + (point.1 as u128));
+fn generate_sensors_and_beacons(input: &str) -> Result<Vec<SensorAndBeacon>, ParseError> {
+    parse_lines(input, |line| SensorAndBeacon::try_from(line))
 }
 
 #[cfg(test)]
 mod test {
+    use crate::day15::{covered_positions, generate_sensors_and_beacons};
+
+    #[test]
+    fn covered_positions_on_example() {
+        let sensors_and_beacons = generate_sensors_and_beacons(include_str!("example")).unwrap();
+        assert_eq!(covered_positions(&sensors_and_beacons, 10), 26);
+    }
+
     mod point {
         use crate::day15::Point;
 
@@ -228,3 +248,11 @@ mod test {
         }
     }
 }
+
+// The puzzle example asks about row 10 while part 1 checks row 2000000, so
+// example2 is the same example moved down by 1999990 rows.
+utils::examples! {
+    Day15,
+    part1: ["example2" => 26],
+    part2: ["example" => 56000011],
+}
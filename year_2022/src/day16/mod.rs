@@ -1,50 +1,27 @@
-use std::{
-    collections::{HashMap, HashSet},
-    hash::Hash,
-    ops::{Deref, DerefMut},
+use std::collections::HashMap;
+
+use utils::{
+    graph::{Distances, Graph},
+    parse::parse_lines,
+    scan,
+    solution::Solution,
+    ParseError,
 };
 
-use utils::{extract_one, lines, read_input};
-
-fn get<'a, T: Hash + Eq, Z>(hashmap: &'a HashMap<T, HashMap<T, Z>>, f: &T, t: &T) -> &'a Z {
-    hashmap.get(f).unwrap().get(t).unwrap()
-}
-fn get_mut<'a, T: Hash + Eq + Clone, Z: Default>(
-    hashmap: &'a mut HashMap<T, HashMap<T, Z>>,
-    f: &T,
-    t: &T,
-) -> &'a mut Z {
-    let inner = hashmap.entry(f.clone()).or_default();
-    inner.entry(t.clone()).or_default()
-}
-
-struct Solver {
-    mapping: HashMap<String, u64>,
-    distances: HashMap<u64, HashMap<u64, usize>>,
-    values: HashMap<u64, usize>,
+pub struct Solver {
+    start: usize,
+    distances: Distances,
+    values: Vec<usize>,
 }
 impl Solver {
-    fn new(
-        mapping: HashMap<String, u64>,
-        distances: HashMap<u64, HashMap<u64, usize>>,
-        values: HashMap<u64, usize>,
-    ) -> Self {
-        Self {
-            mapping,
-            distances,
-            values,
-        }
-    }
     fn solve1(&self) -> usize {
         let mut paths = HashMap::new();
-        let input = *self.mapping.get("AA").unwrap();
-        self.try_permutations(&mut paths, 0, input, 30, 0);
+        self.try_permutations(&mut paths, 0, self.start, 30, 0);
         *paths.values().max().unwrap()
     }
     fn solve2(&self) -> usize {
         let mut paths = HashMap::new();
-        let input = *self.mapping.get("AA").unwrap();
-        self.try_permutations(&mut paths, 0, input, 26, 0);
+        self.try_permutations(&mut paths, 0, self.start, 26, 0);
 
         let mut candidate_paths: Vec<(u64, usize)> =
             paths.into_iter().filter(|(p, _)| p > &0).collect();
@@ -67,19 +44,22 @@ impl Solver {
         &self,
         paths: &mut HashMap<u64, usize>,
         visited_path: u64,
-        actual_node: u64,
+        actual_node: usize,
         time_remaining: usize,
         score: usize,
     ) {
-        for target_node in self.mapping.values() {
-            if visited_path & target_node > 0 {
+        for target_node in 0..self.values.len() {
+            let target_bit = 1 << target_node;
+            if visited_path & target_bit > 0 {
                 continue;
             }
 
-            let distance = *get(&self.distances, &actual_node, target_node);
+            let Some(distance) = self.distances.get(actual_node, target_node) else {
+                continue;
+            };
 
             if time_remaining > distance {
-                let target_flow = *self.values.get(target_node).unwrap();
+                let target_flow = self.values[target_node];
 
                 if target_flow == 0 {
                     continue;
@@ -88,111 +68,87 @@ impl Solver {
                 let time_remaining = time_remaining - distance - 1;
                 let score = score + time_remaining * target_flow;
 
-                let path = visited_path | target_node;
+                let path = visited_path | target_bit;
 
                 let visited_path_score = paths.get(&path).unwrap_or(&0);
                 paths.insert(path, score.max(*visited_path_score));
 
-                self.try_permutations(paths, path, *target_node, time_remaining, score)
+                self.try_permutations(paths, path, target_node, time_remaining, score)
             }
         }
     }
 }
 
-#[derive(Clone, Debug, Eq, PartialEq)]
-struct Path {
-    path: Vec<String>,
-}
-impl Hash for Path {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        self.path.join("").hash(state)
-    }
-}
-impl Deref for Path {
-    type Target = Vec<String>;
+pub struct Day16;
+impl Solution for Day16 {
+    type Input = Solver;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    fn deref(&self) -> &Self::Target {
-        &self.path
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generate_solver(input)
     }
-}
-impl DerefMut for Path {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.path
+    fn part1(solver: &Self::Input) -> Self::Part1 {
+        solver.solve1()
+    }
+    fn part2(solver: &Self::Input) -> Self::Part2 {
+        solver.solve2()
     }
 }
 
-pub fn run() {
-    let mut mapping: HashMap<String, u64> = HashMap::new();
-    let mut connections: HashMap<u64, HashSet<u64>> = HashMap::new();
-    let mut distances: HashMap<u64, HashMap<u64, usize>> = HashMap::new();
-    let mut values: HashMap<u64, usize> = HashMap::new();
-    let mut nodes: HashSet<u64> = HashSet::new();
-
-    lines(read_input!())
-        .into_iter()
-        .enumerate()
-        .for_each(|(idx, input)| {
-            let from = extract_one(&input, "Valve \\w+").replace("Valve ", "");
-            let value = 2_u64.pow(idx as u32);
-            mapping.insert(from.clone(), value);
-        });
-
-    lines(read_input!())
-        .into_iter()
-        .map(parse_line)
-        .enumerate()
-        .for_each(|(_idx, (from, rate, tos))| {
-            let hashset = tos
-                .clone()
-                .into_iter()
-                .map(|it| *mapping.get(&it).unwrap())
-                .collect::<HashSet<u64>>();
-            let from_value = *mapping.get(&from).unwrap();
-
-            *connections.entry(from_value).or_default() = hashset;
-            nodes.insert(from_value);
-            values.insert(from_value, rate);
-        });
-
-    connections.keys().for_each(|f| {
-        connections.keys().for_each(|t| {
-            let distance = if connections.get(f).unwrap().contains(t) {
-                1
-            } else {
-                usize::MAX / 2
-            };
+fn generate_solver(input: &str) -> Result<Solver, ParseError> {
+    let mut graph = Graph::new();
+    let mut rates: HashMap<String, usize> = HashMap::new();
 
-            *get_mut(&mut distances, f, t) = distance;
-        });
+    let valves = parse_lines(input, parse_line)?;
+    valves.iter().for_each(|(from, rate, _)| {
+        rates.insert(from.clone(), *rate);
     });
 
-    for k in connections.keys() {
-        for i in connections.keys() {
-            for j in connections.keys() {
-                let first = *get(&distances, i, k);
-                let second = *get(&distances, k, j);
-                let reference = get_mut(&mut distances, i, j);
-                *reference = (*reference).min(first + second);
+    let lines = input.lines().enumerate().filter(|(_, it)| !it.is_empty());
+    for ((idx, line), (from, _, tos)) in lines.zip(&valves) {
+        for to in tos {
+            if !rates.contains_key(to) {
+                let column = line.rfind(to.as_str()).unwrap_or_default() + 1;
+                return Err(
+                    ParseError::new(column, line, format!("unknown valve {:?}", to))
+                        .offset_lines(idx),
+                );
             }
+            graph.add_edge(from, to, 1);
         }
     }
 
-    let solver = Solver::new(mapping, distances, values);
-    println!("Part1: {}", solver.solve1());
-    println!("Part2: {}", solver.solve2());
+    let graph = graph.compress(|id| graph.label(id) == "AA" || rates[graph.label(id)] > 0);
+
+    let start = graph
+        .id("AA")
+        .ok_or_else(|| ParseError::new(1, "", "missing valve \"AA\""))?;
+    Ok(Solver {
+        start,
+        distances: graph.floyd_warshall(),
+        values: (0..graph.len()).map(|id| rates[graph.label(id)]).collect(),
+    })
+}
+
+fn parse_line(line: &str) -> Result<(String, usize, Vec<String>), ParseError> {
+    let (from, rate, _, tos) = scan!(
+        line,
+        "Valve {} has flow rate={}; {} to {}",
+        String,
+        usize,
+        String,
+        String
+    )?;
+    let tos = tos
+        .split_once(' ')
+        .map(|(_, it)| it.split(", ").map(|it| it.to_string()).collect())
+        .unwrap_or_default();
+    Ok((from, rate, tos))
 }
 
-fn parse_line(line: String) -> (String, usize, Vec<String>) {
-    let rate = extract_one(&line, "rate=\\d+")
-        .replace("rate=", "")
-        .parse()
-        .unwrap();
-    let from = extract_one(&line, "Valve \\w+").replace("Valve ", "");
-    let tos = extract_one(&line, "valves? .*$")
-        .replace("valves ", "")
-        .replace("valve ", "")
-        .split(", ")
-        .map(|it| it.to_string())
-        .collect();
-    (from, rate, tos)
+utils::examples! {
+    Day16,
+    part1: ["example" => 1651],
+    part2: ["example" => 1707],
 }
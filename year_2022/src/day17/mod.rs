@@ -1,25 +1,57 @@
-use std::{
-    collections::{HashMap, HashSet},
-    hash::Hash,
-};
-
-use utils::read_input;
-
-pub fn run() {
-    let lines = read_input!().replace('\n', "");
-    println!("Part1: {}", Cave::from(lines.clone()).solve(2022));
-    println!("Part2: {}", Cave::from(lines.clone()).solve(1000000000000));
+use std::{collections::HashSet, hash::Hash, iter};
+
+use utils::{cycle, parse::parse_lines, solution::Solution, ParseError};
+
+pub struct Day17;
+impl Solution for Day17 {
+    type Input = Vec<Movement>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let lines = parse_lines(input, |line| {
+            line.chars()
+                .enumerate()
+                .map(|(idx, it)| {
+                    Movement::try_from(it).map_err(|error| ParseError::new(idx + 1, line, error))
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })?;
+        let movements: Vec<Movement> = lines.into_iter().flatten().collect();
+        if movements.is_empty() {
+            return Err(ParseError::new(1, "", "expected at least one jet"));
+        }
+        Ok(movements)
+    }
+    fn part1(movements: &Self::Input) -> Self::Part1 {
+        Cave::from(movements.clone()).solve(2022)
+    }
+    fn part2(movements: &Self::Input) -> Self::Part2 {
+        Cave::from(movements.clone()).solve(1000000000000)
+    }
 }
 
 #[derive(Hash, Clone, Copy, Debug, PartialEq, Eq)]
 struct Pos(i64, i64);
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
-enum Movement {
+pub enum Movement {
     Left,
     Right,
     Down,
 }
+impl TryFrom<char> for Movement {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '>' => Ok(Movement::Right),
+            '<' => Ok(Movement::Left),
+            'v' => Ok(Movement::Down),
+            _ => Err(format!("invalid movement {:?}", value)),
+        }
+    }
+}
 
 #[derive(Debug)]
 struct MovementGenerator {
@@ -47,10 +79,10 @@ impl Iterator for MovementGenerator {
                 self.actual_movement = Some(Movement::Down)
             } else {
                 self.step = (self.step + 1) % self.movements.len();
-                self.actual_movement = self.movements.get(self.step).map(Clone::clone);
+                self.actual_movement = self.movements.get(self.step).copied();
             }
         } else {
-            self.actual_movement = self.movements.get(self.step).map(Clone::clone);
+            self.actual_movement = self.movements.get(self.step).copied();
         }
 
         self.tick = !self.tick;
@@ -155,9 +187,6 @@ impl Spawner {
     }
 }
 
-type WindowKey = [(usize, i64, i64, Kind); 5];
-type WindowValue = (i64, i64, Vec<Block>);
-
 #[derive(Debug)]
 struct Cave {
     actual_block: Block,
@@ -168,27 +197,14 @@ struct Cave {
     occupied: HashSet<Pos>,
     iterations: u64,
     blocks: Vec<(usize, Block)>,
-    windows: HashMap<WindowKey, WindowValue>,
-    shortcut_found: bool,
-    target: i64,
 }
-impl From<String> for Cave {
-    fn from(input: String) -> Self {
-        let movements = input
-            .chars()
-            .map(|it| match it {
-                '>' => Movement::Right,
-                '<' => Movement::Left,
-                'v' => Movement::Down,
-                _ => panic!("invalid input"),
-            })
-            .collect();
-        let generator = MovementGenerator::new(movements);
-        Self::new(generator, 0)
+impl From<Vec<Movement>> for Cave {
+    fn from(movements: Vec<Movement>) -> Self {
+        Self::new(MovementGenerator::new(movements))
     }
 }
 impl Cave {
-    fn new(generator: MovementGenerator, target: i64) -> Self {
+    fn new(generator: MovementGenerator) -> Self {
         let mut spawner = Spawner::new();
 
         let mut cave = Self {
@@ -200,23 +216,36 @@ impl Cave {
             occupied: HashSet::new(),
             iterations: 0,
             blocks: Vec::new(),
-            windows: HashMap::new(),
-            shortcut_found: false,
-            target,
         };
 
         cave.insert_row();
         cave
     }
-    fn solve(&mut self, target: i64) -> usize {
-        self.target = target;
-        while !self.shortcut_found {
-            self.iter();
-        }
-        while self.inserted_blocks < self.target {
+    fn solve(&mut self, target: usize) -> usize {
+        let mut heights = vec![self.max_height];
+        let windows = iter::once(self.window()).chain(iter::from_fn(|| {
+            self.drop_block();
+            heights.push(self.max_height);
+            Some(self.window())
+        }));
+        let cycle = cycle::first_repeat(windows).unwrap();
+
+        let growth = heights[cycle.start + cycle.length] - heights[cycle.start];
+        (heights[cycle.extrapolate(target)] + growth * cycle.repeats(target) as i64) as usize
+    }
+    fn drop_block(&mut self) {
+        let inserted_blocks = self.inserted_blocks;
+        while self.inserted_blocks == inserted_blocks {
             self.iter();
         }
-        self.max_height as usize
+    }
+    fn window(&self) -> Vec<(usize, i64, i64, Kind)> {
+        let last_five = &self.blocks[self.blocks.len().saturating_sub(5)..];
+        let bottom = last_five.first().map_or(0, |(_, block)| block.pos.1);
+        last_five
+            .iter()
+            .map(|(step, block)| (*step, block.pos.1 - bottom, block.pos.0, block.kind))
+            .collect()
     }
     fn iter(&mut self) {
         let movement = &self.generator.next();
@@ -248,76 +277,6 @@ impl Cave {
         self.blocks.push((self.generator.step, self.actual_block));
 
         self.actual_block = self.spawner.generate(Pos(3, self.max_height + 4));
-
-        if self.inserted_blocks > 5 {
-            let last_five: Vec<(usize, Block)> =
-                self.blocks.iter().rev().take(5).rev().cloned().collect();
-
-            let actual_blocks: Vec<Block> = self
-                .blocks
-                .iter()
-                .rev()
-                .take(5)
-                .rev()
-                .map(|(_, b)| *b)
-                .collect();
-
-            let window = [
-                (last_five[0].0, 0, last_five[0].1.pos.0, last_five[0].1.kind),
-                (
-                    last_five[1].0,
-                    last_five[1].1.pos.1 - last_five[0].1.pos.1,
-                    last_five[1].1.pos.0,
-                    last_five[1].1.kind,
-                ),
-                (
-                    last_five[2].0,
-                    last_five[2].1.pos.1 - last_five[1].1.pos.1,
-                    last_five[2].1.pos.0,
-                    last_five[2].1.kind,
-                ),
-                (
-                    last_five[3].0,
-                    last_five[3].1.pos.1 - last_five[2].1.pos.1,
-                    last_five[3].1.pos.0,
-                    last_five[3].1.kind,
-                ),
-                (
-                    last_five[4].0,
-                    last_five[4].1.pos.1 - last_five[3].1.pos.1,
-                    last_five[4].1.pos.0,
-                    last_five[4].1.kind,
-                ),
-            ];
-
-            if self.windows.contains_key(&window) && !self.shortcut_found {
-                let (initial_blocks, initial_height, previous_blocks) =
-                    self.windows.get(&window).unwrap().clone();
-
-                let skip_blocks = self.inserted_blocks - initial_blocks;
-                let skip_height = self.max_height - initial_height;
-
-                let count = (self.target - initial_blocks) / skip_blocks;
-
-                self.max_height = initial_height + skip_height * count;
-                self.inserted_blocks = initial_blocks + skip_blocks * count;
-
-                previous_blocks.iter().for_each(|b| {
-                    b.coordinates().iter().for_each(|p| {
-                        self.occupied.insert(Pos(p.0, p.1 + skip_height * count));
-                    })
-                });
-
-                self.actual_block.pos.1 = self.max_height + 4;
-
-                self.shortcut_found = true;
-            }
-
-            self.windows.insert(
-                window,
-                (self.inserted_blocks, self.max_height, actual_blocks),
-            );
-        }
     }
     fn already_occupied(&self, candidate: Block) -> bool {
         candidate
@@ -430,18 +389,32 @@ mod test {
     }
 
     mod cave {
-        use crate::day17::{Block, Cave, Kind, Pos};
+        use utils::solution::Solution;
+
+        use crate::day17::{Block, Cave, Day17, Kind, Pos};
+
+        fn cave(movements: &str) -> Cave {
+            Cave::from(Day17::parse(movements).unwrap())
+        }
 
         #[test]
         fn generate_cave() {
-            let cave = Cave::from("<>".to_string());
+            let cave = cave("<>\n");
 
             assert_eq!(cave.actual_block, Block::new(Pos(3, 4), Kind::Row));
         }
 
+        #[test]
+        fn report_invalid_movements() {
+            let error = Day17::parse("<> \n").unwrap_err();
+
+            assert_eq!((error.line, error.column), (1, 3));
+            assert_eq!(error.message, "invalid movement ' '");
+        }
+
         #[test]
         fn iter_without_obstacles() {
-            let mut cave = Cave::from("<".to_string());
+            let mut cave = cave("<");
 
             cave.iter();
             cave.iter();
@@ -451,7 +424,7 @@ mod test {
 
         #[test]
         fn iter_until_wall() {
-            let mut cave = Cave::from("<".to_string());
+            let mut cave = cave("<");
 
             cave.iter();
             cave.iter();
@@ -466,7 +439,7 @@ mod test {
 
         #[test]
         fn iter_until_floor() {
-            let mut cave = Cave::from("v".to_string());
+            let mut cave = cave("v");
 
             cave.iter();
             cave.iter();
@@ -478,7 +451,7 @@ mod test {
 
         #[test]
         fn iter_until_another_block() {
-            let mut cave = Cave::from("<".to_string());
+            let mut cave = cave("<");
 
             cave.occupied.insert(Pos(2, 4));
 
@@ -489,7 +462,7 @@ mod test {
 
         #[test]
         fn iter_multiple_blocks() {
-            let mut cave = Cave::from("v".to_string());
+            let mut cave = cave("v");
 
             cave.iter();
             cave.iter();
@@ -508,14 +481,20 @@ mod test {
 
         #[test]
         fn part1() {
-            let mut cave = Cave::from(">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>".to_string());
+            let mut cave = cave(">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>");
             assert_eq!(cave.solve(2022), 3068);
         }
 
         #[test]
         fn part2() {
-            let mut cave = Cave::from(">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>".to_string());
+            let mut cave = cave(">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>");
             assert_eq!(cave.solve(1000000000000), 1514285714288);
         }
     }
 }
+
+utils::examples! {
+    Day17,
+    part1: ["example" => 3068],
+    part2: ["example" => 1514285714288_u64],
+}
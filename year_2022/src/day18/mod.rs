@@ -1,95 +1,85 @@
 use std::collections::HashSet;
 
-use utils::{lines, read_input};
+use utils::{geom::Point3, parse::parse_lines, scan, solution::Solution, ParseError};
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
-struct Cube(i32, i32, i32);
-impl Cube {
-    fn is_near(&self, other: &Cube) -> bool {
-        self.0.abs_diff(other.0) + self.1.abs_diff(other.1) + self.2.abs_diff(other.2) == 1
-    }
-    fn neighbours(&self) -> HashSet<Cube> {
-        vec![
-            Cube(self.0 - 1, self.1, self.2),
-            Cube(self.0 + 1, self.1, self.2),
-            Cube(self.0, self.1 + 1, self.2),
-            Cube(self.0, self.1 - 1, self.2),
-            Cube(self.0, self.1, self.2 + 1),
-            Cube(self.0, self.1, self.2 - 1),
-        ]
-        .into_iter()
-        .collect()
-    }
-    fn all_less(&self, other: &Cube) -> bool {
-        self.0 <= other.0 && self.1 <= other.1 && self.2 <= other.2
-    }
-}
-impl From<String> for Cube {
-    fn from(input: String) -> Self {
-        let values: Vec<i32> = input.split(',').map(|it| it.parse().unwrap()).collect();
-        Self(values[0], values[1], values[2])
-    }
-}
+type Cube = Point3<i32>;
 
-pub fn run() {
-    let lava_cubes: HashSet<Cube> = lines(read_input!())
-        .into_iter()
-        .map(|it| it.into())
-        .collect();
+fn all_less(first: &Cube, second: &Cube) -> bool {
+    first.x <= second.x && first.y <= second.y && first.z <= second.z
+}
 
-    part1(&lava_cubes);
-    part2(&lava_cubes);
+fn parse_cube(input: &str) -> Result<Cube, ParseError> {
+    let (x, y, z) = scan!(input, "{},{},{}", u8, u8, u8)?;
+    Ok(Cube::new(x.into(), y.into(), z.into()))
 }
 
-fn part1(lava_cubes: &HashSet<Cube>) {
-    let mut count = lava_cubes.len() * 6;
-    lava_cubes.iter().enumerate().for_each(|(idx, cube1)| {
-        lava_cubes.iter().skip(idx + 1).for_each(|cube2| {
-            if cube1.is_near(cube2) {
-                count -= 2;
-            }
-        })
-    });
+pub struct Day18;
+impl Solution for Day18 {
+    type Input = HashSet<Cube>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    println!("Part1: {}", count);
-}
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generate_lava_cubes(input)
+    }
+    fn part1(lava_cubes: &Self::Input) -> Self::Part1 {
+        let mut count = lava_cubes.len() * 6;
+        lava_cubes.iter().enumerate().for_each(|(idx, cube1)| {
+            lava_cubes.iter().skip(idx + 1).for_each(|cube2| {
+                if cube1.manhattan(cube2) == 1 {
+                    count -= 2;
+                }
+            })
+        });
 
-fn part2(lava_cubes: &HashSet<Cube>) {
-    let mut max_x = 0;
-    let mut max_y = 0;
-    let mut max_z = 0;
+        count
+    }
+    fn part2(lava_cubes: &Self::Input) -> Self::Part2 {
+        let mut max_x = 0;
+        let mut max_y = 0;
+        let mut max_z = 0;
 
-    lava_cubes.iter().for_each(|c| {
-        max_x = max_x.max(c.0);
-        max_y = max_y.max(c.1);
-        max_z = max_z.max(c.2);
-    });
+        lava_cubes.iter().for_each(|c| {
+            max_x = max_x.max(c.x);
+            max_y = max_y.max(c.y);
+            max_z = max_z.max(c.z);
+        });
 
-    let start = Cube(0, 0, 0);
-    let min = Cube(-1, -1, -1);
-    let max = Cube(max_x + 1, max_y + 1, max_z + 1);
+        let start = Cube::new(0, 0, 0);
+        let min = Cube::new(-1, -1, -1);
+        let max = Cube::new(max_x + 1, max_y + 1, max_z + 1);
 
-    let mut air_block_visited: HashSet<Cube> = HashSet::new();
-    let mut queue: Vec<Cube> = vec![start];
-    let mut count = 0;
+        let mut air_block_visited: HashSet<Cube> = HashSet::new();
+        let mut queue: Vec<Cube> = vec![start];
+        let mut count = 0;
 
-    while let Some(air_cube) = queue.pop() {
-        for neighbour in air_cube
-            .neighbours()
-            .iter()
-            .filter(|it| it.all_less(&max) && min.all_less(it))
-            .filter(|it| !air_block_visited.contains(it))
-            .collect::<Vec<_>>()
-        {
-            if lava_cubes.contains(neighbour) {
-                count += 1;
-            } else if !air_block_visited.contains(neighbour) && !queue.contains(neighbour) {
-                queue.push(*neighbour)
+        while let Some(air_cube) = queue.pop() {
+            for neighbour in air_cube
+                .neighbors6()
+                .filter(|it| all_less(it, &max) && all_less(&min, it))
+                .filter(|it| !air_block_visited.contains(it))
+                .collect::<Vec<_>>()
+            {
+                if lava_cubes.contains(&neighbour) {
+                    count += 1;
+                } else if !air_block_visited.contains(&neighbour) && !queue.contains(&neighbour) {
+                    queue.push(neighbour)
+                }
             }
+
+            air_block_visited.insert(air_cube);
         }
 
-        air_block_visited.insert(air_cube);
+        count
     }
+}
+
+fn generate_lava_cubes(input: &str) -> Result<HashSet<Cube>, ParseError> {
+    Ok(parse_lines(input, parse_cube)?.into_iter().collect())
+}
 
-    println!("Part2: {}", count);
+utils::examples! {
+    Day18,
+    part1: ["example" => 64],
+    part2: ["example" => 58],
 }
@@ -1,29 +1,33 @@
 use std::collections::{HashMap, HashSet, VecDeque};
 
-use utils::{extract, extract_one, lines, read_input};
+use utils::{parse::parse_lines, scan, solution::Solution, ParseError};
 
-pub fn run() {
-    let blueprints: Vec<Blueprint> = lines(read_input!())
-        .into_iter()
-        .map(Blueprint::from)
-        .collect();
+pub struct Day19;
+impl Solution for Day19 {
+    type Input = Vec<Blueprint>;
+    type Part1 = i32;
+    type Part2 = i32;
 
-    println!(
-        "Part1: {}",
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generate_blueprints(input)
+    }
+    fn part1(blueprints: &Self::Input) -> Self::Part1 {
         blueprints
             .iter()
             .map(|it| it.idx * execute_blueprint(it.clone(), 24))
             .sum::<i32>()
-    );
-
-    println!(
-        "Part2: {}",
+    }
+    fn part2(blueprints: &Self::Input) -> Self::Part2 {
         blueprints
             .iter()
             .take(3)
             .map(|it| execute_blueprint(it.clone(), 32))
             .product::<i32>()
-    );
+    }
+}
+
+fn generate_blueprints(input: &str) -> Result<Vec<Blueprint>, ParseError> {
+    parse_lines(input, |line| Blueprint::try_from(line))
 }
 
 fn execute_blueprint(blueprint: Blueprint, end: i32) -> i32 {
@@ -97,7 +101,7 @@ fn execute_blueprint(blueprint: Blueprint, end: i32) -> i32 {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
-struct Blueprint {
+pub struct Blueprint {
     idx: i32,
     iterations: i32,
     robots: Vec<i32>,
@@ -153,27 +157,36 @@ impl Blueprint {
             .unwrap()
     }
 }
-impl From<String> for Blueprint {
-    fn from(input: String) -> Self {
+impl TryFrom<&str> for Blueprint {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
         let robots = vec![0, 0, 0, 1];
         let ores = vec![0, 0, 0, 0];
-        let prefix = extract_one(&input, "Blueprint \\d+");
-        let numbers: Vec<i32> = extract(&input.replace(&prefix, ""), "\\d+")
-            .into_iter()
-            .map(|it| it.parse().unwrap())
-            .collect();
-        let idx = prefix.replace("Blueprint ", "").parse().unwrap();
-        let ore_costs = vec![0, 0, 0, numbers[0]];
-        let clay_costs = vec![0, 0, 0, numbers[1]];
-        let obsidian_costs = vec![0, 0, numbers[3], numbers[2]];
-        let geode_costs = vec![0, numbers[5], 0, numbers[4]];
-
-        Self::new(
+        let (idx, ore, clay, obsidian_ore, obsidian_clay, geode_ore, geode_obsidian) = scan!(
+            input,
+            "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. \
+             Each obsidian robot costs {} ore and {} clay. \
+             Each geode robot costs {} ore and {} obsidian.",
+            i32,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8
+        )?;
+        let ore_costs = vec![0, 0, 0, ore.into()];
+        let clay_costs = vec![0, 0, 0, clay.into()];
+        let obsidian_costs = vec![0, 0, obsidian_clay.into(), obsidian_ore.into()];
+        let geode_costs = vec![0, geode_obsidian.into(), 0, geode_ore.into()];
+
+        Ok(Self::new(
             idx,
             vec![geode_costs, obsidian_costs, clay_costs, ore_costs],
             robots,
             ores,
-        )
+        ))
     }
 }
 
@@ -184,7 +197,7 @@ mod tests {
 
     #[test]
     fn parse_blueprint() {
-        let blueprint = Blueprint::from("Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.".to_string());
+        let blueprint = Blueprint::try_from("Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.").unwrap();
 
         assert_eq!(
             blueprint.costs,
@@ -263,3 +276,9 @@ mod tests {
         }
     }
 }
+
+utils::examples! {
+    Day19,
+    part1: ["example" => 33],
+    part2: ["example" => 3472],
+}
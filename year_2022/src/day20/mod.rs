@@ -1,24 +1,43 @@
-use utils::{lines, read_input};
+use utils::{
+    parse::{field, parse_lines},
+    solution::Solution,
+    ParseError,
+};
 
-pub fn run() {
-    let input1 = lines(read_input!())
-        .into_iter()
-        .map(|it| it.parse::<i128>().unwrap())
-        .enumerate()
-        .collect::<Vec<_>>();
+pub struct Day20;
+impl Solution for Day20 {
+    type Input = Vec<i128>;
+    type Part1 = i128;
+    type Part2 = i128;
 
-    let input2 = lines(read_input!())
-        .into_iter()
-        .map(|it| it.parse::<i128>().unwrap() * 811589153)
-        // .map(|it| it.parse::<i128>().unwrap())
-        .enumerate()
-        .collect::<Vec<_>>();
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let numbers = parse_lines(input, |line| field::<i64>(line, 1, line))?;
+        if numbers.len() < 2 || numbers.iter().filter(|&&it| it == 0).count() != 1 {
+            return Err(ParseError::new(
+                1,
+                "",
+                "expected at least two numbers and exactly one 0",
+            ));
+        }
+        Ok(numbers.into_iter().map(i128::from).collect())
+    }
+    fn part1(numbers: &Self::Input) -> Self::Part1 {
+        let input = numbers.iter().copied().enumerate().collect::<Vec<_>>();
 
-    println!("Part1: {}", part1(input1, 1));
-    println!("Part2: {}", part1(input2, 10));
+        mix(input, 1)
+    }
+    fn part2(numbers: &Self::Input) -> Self::Part2 {
+        let input = numbers
+            .iter()
+            .map(|it| it * 811589153)
+            .enumerate()
+            .collect::<Vec<_>>();
+
+        mix(input, 10)
+    }
 }
 
-fn part1(input: Vec<(usize, i128)>, iterations: usize) -> i128 {
+fn mix(input: Vec<(usize, i128)>, iterations: usize) -> i128 {
     let mut input = input;
     for _iteration in 0..iterations {
         for idx in 0..input.len() {
@@ -37,7 +56,7 @@ fn part1(input: Vec<(usize, i128)>, iterations: usize) -> i128 {
     result_new.into_iter().sum::<i128>()
 }
 
-fn do_swap(input: &mut Vec<(usize, i128)>, reference: usize) {
+fn do_swap(input: &mut [(usize, i128)], reference: usize) {
     let (old_pos, (_, movement)) = find(input, |(idx, _)| idx == &reference);
     let old_pos = old_pos as i128;
 
@@ -74,3 +93,9 @@ fn find(vec: &[(usize, i128)], filter: impl Fn(&(usize, i128)) -> bool) -> (usiz
         .find(|(_, data)| filter(data))
         .unwrap()
 }
+
+utils::examples! {
+    Day20,
+    part1: ["example" => 3],
+    part2: ["example" => 1623178306_u64],
+}
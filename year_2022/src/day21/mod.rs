@@ -3,7 +3,13 @@ use std::{
     fmt::Display,
 };
 
-use utils::{extract_one, lines, matches, read_input};
+use utils::{
+    answer::Answer,
+    parse::{field, parse_lines},
+    scan,
+    solution::Solution,
+    ParseError,
+};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 enum Op {
@@ -28,14 +34,16 @@ impl Op {
         }
     }
 }
-impl From<&str> for Op {
-    fn from(input: &str) -> Self {
+impl TryFrom<&str> for Op {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
         match input {
-            "+" => Op::Add,
-            "-" => Op::Subtract,
-            "*" => Op::Multiply,
-            "/" => Op::Divide,
-            a => panic!("invalid input {}", a),
+            "+" => Ok(Op::Add),
+            "-" => Ok(Op::Subtract),
+            "*" => Ok(Op::Multiply),
+            "/" => Ok(Op::Divide),
+            _ => Err(ParseError::new(1, input, "expected +, -, * or /")),
         }
     }
 }
@@ -58,26 +66,17 @@ enum Value {
     Equality(String, String),
     Unknown,
 }
-impl From<String> for Value {
-    fn from(input: String) -> Self {
-        if input.trim().is_empty() {
-            Value::Unknown
-        } else if matches(&input, "\\d+") {
-            Value::Int(input.parse().unwrap())
-        } else if matches(&input, ".*=.*") {
-            let mut iter = input.split(' ');
-            let first = iter.next().unwrap().to_string();
-            iter.next().unwrap().to_string();
-            let second = iter.next().unwrap().to_string();
-            Value::Equality(first, second)
-        } else {
-            let mut iter = input.split(' ');
-            Value::Operation(
-                iter.next().unwrap().to_string(),
-                iter.next().unwrap().into(),
-                iter.next().unwrap().to_string(),
-            )
+impl TryFrom<&str> for Value {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        if input.starts_with(|it: char| it.is_ascii_digit()) {
+            return Ok(Value::Int(field::<i64>(input, 1, input)?.into()));
         }
+        let (first, op, second) = scan!(input, "{} {} {}", String, String, String)?;
+        let op = Op::try_from(op.as_str())
+            .map_err(|error| ParseError::new(first.len() + 2, input, error.message))?;
+        Ok(Value::Operation(first, op, second))
     }
 }
 impl Display for Value {
@@ -100,7 +99,7 @@ impl Display for Value {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
-struct Monkey {
+pub struct Monkey {
     name: String,
     value: Value,
 }
@@ -160,11 +159,28 @@ impl Display for Monkey {
         write!(f, "{}: {}", self.name, self.value)
     }
 }
-impl From<String> for Monkey {
-    fn from(input: String) -> Self {
-        let name = extract_one(&input, "\\w+");
-        let value = extract_one(&input, ": .*").replace(": ", "").into();
-        Monkey { name, value }
+impl From<Monkey> for Answer {
+    fn from(monkey: Monkey) -> Self {
+        match monkey.value {
+            Value::Int(value) => Answer::Int(value),
+            _ => Answer::Text(monkey.to_string()),
+        }
+    }
+}
+impl TryFrom<&str> for Monkey {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let (name, value) = input
+            .split_once(": ")
+            .ok_or_else(|| ParseError::new(1, input, "expected \"name: job\""))?;
+        let value = Value::try_from(value).map_err(|error| {
+            ParseError::new(name.len() + 2 + error.column, input, error.message)
+        })?;
+        Ok(Monkey {
+            name: name.to_string(),
+            value,
+        })
     }
 }
 
@@ -249,28 +265,75 @@ impl Monkeys {
     }
 }
 
-pub fn run() {
-    let monkeys = lines(read_input!())
-        .into_iter()
-        .map(|it| it.into())
-        .map(|it: Monkey| (it.name.clone(), it))
-        .collect::<HashMap<String, Monkey>>();
+pub struct Day21;
+impl Solution for Day21 {
+    type Input = HashMap<String, Monkey>;
+    type Part1 = Monkey;
+    type Part2 = Monkey;
 
-    let modified_monkeys = monkeys
-        .iter()
-        .map(|(name, monkey)| {
-            let new_monkey = match &name[..] {
-                "root" => monkey.to_eq().clone(),
-                "humn" => Monkey::unknown(name.to_string()),
-                _ => monkey.clone(),
-            };
-            (name.clone(), new_monkey)
-        })
-        .collect::<HashMap<_, _>>();
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generate_monkeys(input)
+    }
+    fn part1(monkeys: &Self::Input) -> Self::Part1 {
+        Monkeys::new(monkeys.clone()).calculate("root")
+    }
+    fn part2(monkeys: &Self::Input) -> Self::Part2 {
+        let modified_monkeys = monkeys
+            .iter()
+            .map(|(name, monkey)| {
+                let new_monkey = match &name[..] {
+                    "root" => monkey.to_eq().clone(),
+                    "humn" => Monkey::unknown(name.to_string()),
+                    _ => monkey.clone(),
+                };
+                (name.clone(), new_monkey)
+            })
+            .collect::<HashMap<_, _>>();
 
-    println!("Part1: {}", Monkeys::new(monkeys).calculate("root"));
-    println!(
-        "Part2: {}",
         Monkeys::new(modified_monkeys).calculate("humn")
-    );
+    }
+}
+
+fn generate_monkeys(input: &str) -> Result<HashMap<String, Monkey>, ParseError> {
+    let list = parse_lines(input, |line| Monkey::try_from(line))?;
+    let monkeys: HashMap<String, Monkey> = list
+        .iter()
+        .map(|it| (it.name.clone(), it.clone()))
+        .collect();
+
+    let lines = input.lines().enumerate().filter(|(_, it)| !it.is_empty());
+    for ((idx, line), monkey) in lines.zip(&list) {
+        if let Value::Operation(first, _, second) = &monkey.value {
+            if let Some(name) = [first, second]
+                .into_iter()
+                .find(|it| !monkeys.contains_key(*it))
+            {
+                let column = line.rfind(name.as_str()).unwrap_or_default() + 1;
+                return Err(
+                    ParseError::new(column, line, format!("unknown monkey {:?}", name))
+                        .offset_lines(idx),
+                );
+            }
+        }
+    }
+    if !matches!(
+        monkeys.get("root").map(|it| &it.value),
+        Some(Value::Operation(..))
+    ) {
+        return Err(ParseError::new(
+            1,
+            "",
+            "expected a \"root\" monkey with an operation",
+        ));
+    }
+    if !monkeys.contains_key("humn") {
+        return Err(ParseError::new(1, "", "missing monkey \"humn\""));
+    }
+    Ok(monkeys)
+}
+
+utils::examples! {
+    Day21,
+    part1: ["example" => 152],
+    part2: ["example" => 301],
 }
@@ -1,6 +1,10 @@
 use std::collections::HashMap;
 
-use utils::{extract, lines, read_input};
+use utils::{
+    parse::{field, paragraphs},
+    solution::Solution,
+    ParseError,
+};
 
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 struct Pos(i32, i32);
@@ -58,27 +62,41 @@ enum Step {
     Left,
     Right,
 }
-impl From<String> for Step {
-    fn from(input: String) -> Self {
-        match &input[..] {
-            "R" => Step::Right,
-            "L" => Step::Left,
-            value => Step::Straight(value.parse().unwrap()),
+impl TryFrom<&str> for Step {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        match input {
+            "R" => Ok(Step::Right),
+            "L" => Ok(Step::Left),
+            value => Ok(Step::Straight(field(input, 1, value)?)),
         }
     }
 }
 
 #[derive(Debug)]
-struct Steps {
+pub struct Steps {
     steps: Vec<Step>,
 }
-impl From<String> for Steps {
-    fn from(input: String) -> Self {
-        let steps = extract(&input, "(\\d+|R|L)")
-            .into_iter()
-            .map(|it| it.into())
-            .collect::<Vec<_>>();
-        Self { steps }
+impl TryFrom<&str> for Steps {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let mut steps = vec![];
+        let mut column = 0;
+        while column < input.len() {
+            let rest = &input[column..];
+            let len = match rest.find(|it: char| !it.is_ascii_digit()) {
+                Some(0) => rest.chars().next().map_or(1, char::len_utf8),
+                Some(len) => len,
+                None => rest.len(),
+            };
+            let step = Step::try_from(&rest[..len])
+                .map_err(|_| ParseError::new(column + 1, input, "expected a number, R or L"))?;
+            steps.push(step);
+            column += len;
+        }
+        Ok(Self { steps })
     }
 }
 
@@ -99,8 +117,8 @@ impl TryFrom<char> for Kind {
     }
 }
 
-#[derive(Debug)]
-struct Map {
+#[derive(Clone, Debug)]
+pub struct Map {
     fields: HashMap<Pos, Kind>,
     actual_pos: Pos,
     max_x: i32,
@@ -297,11 +315,11 @@ impl From<&Map> for Roll {
 impl PosCalculator for Roll {
     fn next_pos(&self, from: Pos, dir: Dir) -> (Pos, Dir) {
         let next_pos = from.move_to(&dir, 1);
-        if self.fields.get(&next_pos).is_some() {
+        if self.fields.contains_key(&next_pos) {
             (next_pos, dir)
         } else {
             let mut rolled = self.roll(&next_pos, &dir);
-            while self.fields.get(&rolled).is_none() {
+            while !self.fields.contains_key(&rolled) {
                 rolled = rolled.move_to(&dir, 1);
             }
             (rolled, dir)
@@ -421,25 +439,50 @@ impl Cube {
     }
 }
 
-pub fn run() {
-    let mut lines = lines(read_input!());
-    let steps = Steps::from(lines.last().unwrap().clone());
+pub struct Day22;
+impl Solution for Day22 {
+    type Input = (Map, Steps);
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generate_data(input)
+    }
+    fn part1((map, steps): &Self::Input) -> Self::Part1 {
+        let mut map = map.clone();
+        let roll = Roll::from(&map);
+
+        steps.steps.iter().for_each(|step| map.do_step(step, &roll));
+
+        map.value()
+    }
+    fn part2((map, steps): &Self::Input) -> Self::Part2 {
+        let mut map = map.clone();
+        let cube = Cube::from(&map);
+
+        steps.steps.iter().for_each(|step| map.do_step(step, &cube));
 
-    lines.pop();
-    let mut first_map = Map::from(lines.clone());
-    let roll = Roll::from(&first_map);
+        map.value()
+    }
+}
 
-    let mut second_map = Map::from(lines);
-    let cube = Cube::from(&second_map);
+fn generate_data(input: &str) -> Result<(Map, Steps), ParseError> {
+    let [map, steps] = paragraphs(input)[..] else {
+        return Err(ParseError::new(1, "", "expected a map and a path"));
+    };
+    let rows: Vec<String> = map.lines().map(|it| it.to_string()).collect();
 
-    let step_iter = steps.steps.iter();
-    for step in step_iter {
-        first_map.do_step(step, &roll);
-        second_map.do_step(step, &cube);
+    for (y, row) in rows.iter().enumerate() {
+        if let Some(x) = row.find(|it| !matches!(it, ' ' | '.' | '#')) {
+            return Err(ParseError::new(x + 1, row, "expected a tile").offset_lines(y));
+        }
+    }
+    if !rows.iter().any(|it| it.contains('.')) {
+        return Err(ParseError::new(1, "", "the map has no open tile"));
     }
+    let steps = Steps::try_from(steps).map_err(|error| error.offset_lines(rows.len() + 1))?;
 
-    println!("Part1: {}", first_map.value());
-    println!("Part2: {}", second_map.value());
+    Ok((Map::from(rows), steps))
 }
 
 #[cfg(test)]
@@ -576,3 +619,9 @@ mod tests {
         }
     }
 }
+
+utils::examples! {
+    Day22,
+    part1: ["example" => 6032],
+    part2: ["example" => 5031],
+}
@@ -1,6 +1,6 @@
 use std::collections::{HashMap, HashSet};
 
-use utils::{lines, read_input};
+use utils::{parse::parse_lines, solution::Solution, ParseError};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 enum Dir {
@@ -23,8 +23,8 @@ impl Pos {
     }
 }
 
-#[derive(Debug)]
-struct Elves {
+#[derive(Clone, Debug)]
+pub struct Elves {
     elves: HashSet<Pos>,
     preferred_dirs: Vec<Dir>,
     rounds: usize,
@@ -129,36 +129,65 @@ impl Elves {
         (max_y + 1 - min_y) * (max_x + 1 - min_x) - self.elves.len() as i32
     }
 }
-impl From<Vec<String>> for Elves {
-    fn from(input: Vec<String>) -> Self {
-        let mut elves: HashSet<Pos> = HashSet::new();
-        input.iter().enumerate().for_each(|(y, row)| {
-            row.chars().enumerate().for_each(|(x, c)| {
-                if matches!(c, '#') {
-                    elves.insert(Pos(x as i32, y as i32));
-                }
-            });
-        });
-        Self {
+impl TryFrom<&str> for Elves {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let rows = parse_lines(input, |row| {
+            row.chars()
+                .enumerate()
+                .map(|(x, c)| match c {
+                    '#' => Ok(true),
+                    '.' => Ok(false),
+                    _ => Err(ParseError::new(x + 1, row, "expected '#' or '.'")),
+                })
+                .collect::<Result<Vec<bool>, ParseError>>()
+        })?;
+        let elves: HashSet<Pos> = rows
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.iter()
+                    .enumerate()
+                    .filter(|(_, &elf)| elf)
+                    .map(move |(x, _)| Pos(x as i32, y as i32))
+            })
+            .collect();
+        if elves.is_empty() {
+            return Err(ParseError::new(1, "", "expected at least one elf"));
+        }
+        Ok(Self {
             elves,
             rounds: 0,
             completed: false,
             preferred_dirs: vec![Dir::North, Dir::South, Dir::West, Dir::East],
-        }
+        })
     }
 }
 
-pub fn run() {
-    let mut elves = Elves::from(lines(read_input!()));
-    for _ in 0..10 {
-        elves.run();
-    }
-    println!("Part1: {}", elves.value());
+pub struct Day23;
+impl Solution for Day23 {
+    type Input = Elves;
+    type Part1 = i32;
+    type Part2 = usize;
 
-    while !elves.completed {
-        elves.run();
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Elves::try_from(input)
+    }
+    fn part1(elves: &Self::Input) -> Self::Part1 {
+        let mut elves = elves.clone();
+        for _ in 0..10 {
+            elves.run();
+        }
+        elves.value()
+    }
+    fn part2(elves: &Self::Input) -> Self::Part2 {
+        let mut elves = elves.clone();
+        while !elves.completed {
+            elves.run();
+        }
+        elves.rounds
     }
-    println!("Part2: {}", elves.rounds);
 }
 
 #[cfg(test)]
@@ -166,16 +195,13 @@ mod tests {
     mod elves {
         use crate::day23::{Elves, Pos};
 
+        fn elves(rows: &[&str]) -> Elves {
+            Elves::try_from(rows.join("\n").as_str()).unwrap()
+        }
+
         #[test]
         fn build_elves() {
-            let elves = Elves::from(vec![
-                ".....".to_string(),
-                "..##.".to_string(),
-                "..#..".to_string(),
-                ".....".to_string(),
-                "..##.".to_string(),
-                ".....".to_string(),
-            ]);
+            let elves = elves(&[".....", "..##.", "..#..", ".....", "..##.", "....."]);
 
             assert_eq!(
                 elves.elves,
@@ -187,12 +213,7 @@ mod tests {
 
         #[test]
         fn do_not_move_if_no_neighbours() {
-            let mut elves = Elves::from(vec![
-                "....".to_string(),
-                ".#..".to_string(),
-                "....".to_string(),
-                "....".to_string(),
-            ]);
+            let mut elves = elves(&["....", ".#..", "....", "...."]);
 
             elves.run();
             assert_eq!(elves.elves, vec![Pos(1, 1)].into_iter().collect());
@@ -200,12 +221,7 @@ mod tests {
 
         #[test]
         fn preferred_pos() {
-            let elves = Elves::from(vec![
-                "....".to_string(),
-                ".#..".to_string(),
-                ".#..".to_string(),
-                "....".to_string(),
-            ]);
+            let elves = elves(&["....", ".#..", ".#..", "...."]);
 
             assert_eq!(elves.preferred_pos(&Pos(1, 1)), Pos(1, 0));
             assert_eq!(elves.preferred_pos(&Pos(1, 2)), Pos(1, 3));
@@ -213,12 +229,7 @@ mod tests {
 
         #[test]
         fn move_elves_with_preferred_pos() {
-            let mut elves = Elves::from(vec![
-                "....".to_string(),
-                ".#..".to_string(),
-                ".#..".to_string(),
-                "....".to_string(),
-            ]);
+            let mut elves = elves(&["....", ".#..", ".#..", "...."]);
 
             elves.run();
             assert_eq!(
@@ -229,12 +240,7 @@ mod tests {
 
         #[test]
         fn do_not_move_elves_with_preferred_pos() {
-            let mut elves = Elves::from(vec![
-                ".#..".to_string(),
-                ".#..".to_string(),
-                "....".to_string(),
-                ".##.".to_string(),
-            ]);
+            let mut elves = elves(&[".#..", ".#..", "....", ".##."]);
 
             elves.run();
             assert_eq!(
@@ -246,3 +252,9 @@ mod tests {
         }
     }
 }
+
+utils::examples! {
+    Day23,
+    part1: ["example" => 110],
+    part2: ["example" => 20],
+}
@@ -3,7 +3,7 @@ use std::{
     ops::{Deref, DerefMut},
 };
 
-use utils::{lines, read_input};
+use utils::{parse::parse_lines, solution::Solution, ParseError};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum Dir {
@@ -172,7 +172,7 @@ struct Step {
 }
 
 #[derive(Clone, Debug)]
-struct Map {
+pub struct Map {
     blizzards: BlizzardsHistory,
     actual: Pos,
     max_x: i32,
@@ -285,15 +285,63 @@ impl From<Vec<String>> for Map {
     }
 }
 
-pub fn run() {
-    let mut map = Map::from(lines(read_input!()));
+fn validate(rows: &[String]) -> Result<(), ParseError> {
+    let (width, height) = (rows.first().map_or(0, |it| it.len()), rows.len());
+    if width < 3 || height < 3 {
+        return Err(ParseError::new(1, "", "expected a valley of at least 3 by 3"));
+    }
+    for (y, row) in rows.iter().enumerate() {
+        let error = |x: usize, message: &str| ParseError::new(x + 1, row, message).offset_lines(y);
+        if row.len() != width {
+            return Err(error(0, "rows must have the same length"));
+        }
+        for (x, c) in row.chars().enumerate() {
+            let gate = (x, y) == (1, 0) || (x, y) == (width - 2, height - 1);
+            let wall = x == 0 || y == 0 || x == width - 1 || y == height - 1;
+            match (c, gate, wall) {
+                ('.', true, _) | ('#', false, true) => {}
+                (_, true, _) => return Err(error(x, "expected an opening")),
+                (_, _, true) => return Err(error(x, "expected a wall")),
+                ('.' | '^' | '>' | 'v' | '<', _, _) => {}
+                _ => return Err(error(x, "expected a blizzard or an open tile")),
+            }
+        }
+    }
+    Ok(())
+}
+
+pub struct Day24;
+impl Solution for Day24 {
+    type Input = Map;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let end = Pos(map.max_x, map.max_y + 1);
-    let start = Pos(1, 0);
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let rows = parse_lines(input, |row| Ok(row.to_string()))?;
+        validate(&rows)?;
+        Ok(Map::from(rows))
+    }
+    fn part1(map: &Self::Input) -> Self::Part1 {
+        let mut map = map.clone();
+        let end = Pos(map.max_x, map.max_y + 1);
+
+        map.reach(&end);
+        map.iterations
+    }
+    fn part2(map: &Self::Input) -> Self::Part2 {
+        let mut map = map.clone();
+        let end = Pos(map.max_x, map.max_y + 1);
+        let start = Pos(1, 0);
+
+        map.reach(&end);
+        map.reach(&start);
+        map.reach(&end);
+        map.iterations
+    }
+}
 
-    map.reach(&end);
-    println!("Part1: {}", map.iterations);
-    map.reach(&start);
-    map.reach(&end);
-    println!("Part2: {}", map.iterations);
+utils::examples! {
+    Day24,
+    part1: ["example" => 18],
+    part2: ["example" => 54],
 }
@@ -1,11 +1,27 @@
 use std::{collections::HashMap, fmt::Display, iter::Sum};
 
-use utils::{lines, read_input};
-
-pub fn run() {
-    let snafus: Vec<Snafu> = lines(read_input!()).into_iter().map(Snafu::from).collect();
-    let sum = snafus.iter().sum::<Snafu>();
-    println!("Part1: {}", sum);
+use utils::{
+    answer::Answer,
+    parse::parse_lines,
+    solution::{Solution, Unsolved},
+    ParseError,
+};
+
+pub struct Day25;
+impl Solution for Day25 {
+    type Input = Vec<Snafu>;
+    type Part1 = Snafu;
+    type Part2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, |line| Snafu::try_from(line))
+    }
+    fn part1(snafus: &Self::Input) -> Self::Part1 {
+        snafus.iter().sum::<Snafu>()
+    }
+    fn part2(_snafus: &Self::Input) -> Self::Part2 {
+        Unsolved
+    }
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -25,7 +41,7 @@ impl From<Snafu> for Decimal {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
-struct Snafu {
+pub struct Snafu {
     values: Vec<i64>,
 }
 impl Snafu {
@@ -62,20 +78,31 @@ impl Display for Snafu {
         write!(f, "{}", value)
     }
 }
-impl From<String> for Snafu {
-    fn from(input: String) -> Self {
-        let values: Vec<i64> = input
+impl From<Snafu> for Answer {
+    fn from(snafu: Snafu) -> Self {
+        Answer::Text(snafu.to_string())
+    }
+}
+impl TryFrom<&str> for Snafu {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        if input.len() > 20 {
+            return Err(ParseError::new(21, input, "too many digits"));
+        }
+        let values = input
             .chars()
-            .map(|it| match it {
-                '2' => 2,
-                '1' => 1,
-                '0' => 0,
-                '-' => -1,
-                '=' => -2,
-                _ => panic!("invalid input"),
+            .enumerate()
+            .map(|(idx, it)| match it {
+                '2' => Ok(2),
+                '1' => Ok(1),
+                '0' => Ok(0),
+                '-' => Ok(-1),
+                '=' => Ok(-2),
+                _ => Err(ParseError::new(idx + 1, input, "expected a SNAFU digit")),
             })
-            .collect();
-        Self::new(values)
+            .collect::<Result<Vec<i64>, ParseError>>()?;
+        Ok(Self::new(values))
     }
 }
 impl From<Decimal> for Snafu {
@@ -101,7 +128,7 @@ impl From<Decimal> for Snafu {
             x -= candidate * digit * xs;
         }
 
-        let values = (0..=*value.keys().max().unwrap())
+        let values = (0..=value.keys().max().copied().unwrap_or_default())
             .map(|it| *value.get(&it).unwrap_or(&0))
             .rev()
             .collect::<Vec<i64>>();
@@ -136,18 +163,16 @@ mod tests {
     use crate::day25::{Decimal, Snafu};
 
     fn snafu(input: &str) -> Snafu {
-        Snafu::from(input.to_string())
+        Snafu::try_from(input).unwrap()
     }
 
     #[test]
     fn convert_string_to_snafu() {
-        assert_eq!(Snafu::from("1".to_string()), Snafu::new(vec![1]));
-        assert_eq!(Snafu::from("2".to_string()), Snafu::new(vec![2]));
-        assert_eq!(Snafu::from("1=".to_string()), Snafu::new(vec![1, -2]));
-        assert_eq!(
-            Snafu::from("1-0---0".to_string()),
-            Snafu::new(vec![1, -1, 0, -1, -1, -1, 0])
-        );
+        assert_eq!(snafu("1"), Snafu::new(vec![1]));
+        assert_eq!(snafu("2"), Snafu::new(vec![2]));
+        assert_eq!(snafu("1="), Snafu::new(vec![1, -2]));
+        assert_eq!(snafu("1-0---0"), Snafu::new(vec![1, -1, 0, -1, -1, -1, 0]));
+        assert_eq!(Snafu::try_from("1x").unwrap_err().column, 2);
     }
 
     #[test]
@@ -160,9 +185,16 @@ mod tests {
 
     #[test]
     fn convert_to_snafu() {
+        assert_eq!(Snafu::from(Decimal(0)), snafu("0"));
         assert_eq!(Snafu::from(Decimal(1)), snafu("1"));
         assert_eq!(Snafu::from(Decimal(2022)), snafu("1=11-2"));
         assert_eq!(Snafu::from(Decimal(12345)), snafu("1-0---0"));
         assert_eq!(Snafu::from(Decimal(314159265)), snafu("1121-1110-1=0"));
     }
 }
+
+// Day 25 has no second puzzle, part 2 stays Unsolved and has nothing to check.
+utils::examples! {
+    Day25,
+    part1: ["example" => "2=-1=0"],
+}
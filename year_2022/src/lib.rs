@@ -0,0 +1,55 @@
+use utils::registry::Registry;
+
+mod day01;
+mod day02;
+mod day03;
+mod day04;
+mod day05;
+mod day06;
+mod day07;
+mod day08;
+mod day09;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day16;
+mod day17;
+mod day18;
+mod day19;
+mod day20;
+mod day21;
+mod day22;
+mod day23;
+mod day24;
+mod day25;
+
+pub fn register(registry: &mut Registry) {
+    registry.add::<day01::Day01>(2022, 1);
+    registry.add::<day02::Day02>(2022, 2);
+    registry.add::<day03::Day03>(2022, 3);
+    registry.add::<day04::Day04>(2022, 4);
+    registry.add::<day05::Day05>(2022, 5);
+    registry.add::<day06::Day06>(2022, 6);
+    registry.add::<day07::Day07>(2022, 7);
+    registry.add::<day08::Day08>(2022, 8);
+    registry.add::<day09::Day09>(2022, 9);
+    registry.add::<day10::Day10>(2022, 10);
+    registry.add::<day11::Day11>(2022, 11);
+    registry.add::<day12::Day12>(2022, 12);
+    registry.add::<day13::Day13>(2022, 13);
+    registry.add::<day14::Day14>(2022, 14);
+    registry.add::<day15::Day15>(2022, 15);
+    registry.add::<day16::Day16>(2022, 16);
+    registry.add::<day17::Day17>(2022, 17);
+    registry.add::<day18::Day18>(2022, 18);
+    registry.add::<day19::Day19>(2022, 19);
+    registry.add::<day20::Day20>(2022, 20);
+    registry.add::<day21::Day21>(2022, 21);
+    registry.add::<day22::Day22>(2022, 22);
+    registry.add::<day23::Day23>(2022, 23);
+    registry.add::<day24::Day24>(2022, 24);
+    registry.add::<day25::Day25>(2022, 25);
+}
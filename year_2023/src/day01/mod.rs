@@ -1,15 +1,37 @@
 use std::convert::identity;
 
-use utils::{lines, read_input};
+use utils::{parse::parse_lines, solution::Solution, ParseError};
 
-pub fn run() {
-    println!("Part1: {}", run_part(lines(read_input!()), identity));
-    println!("Part2: {}", run_part(lines(read_input!()), replace_digits));
+pub struct Day01;
+impl Solution for Day01 {
+    type Input = Vec<String>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, |line| {
+            match line.find(|it: char| !it.is_ascii_alphanumeric()) {
+                Some(idx) => Err(ParseError::new(
+                    idx + 1,
+                    line,
+                    "expected a letter or a digit",
+                )),
+                None => Ok(line.to_string()),
+            }
+        })
+    }
+    fn part1(lines: &Self::Input) -> Self::Part1 {
+        run_part(lines, identity)
+    }
+    fn part2(lines: &Self::Input) -> Self::Part2 {
+        run_part(lines, replace_digits)
+    }
 }
 
-fn run_part(input: Vec<String>, mapper: impl Fn(String) -> String) -> u32 {
+fn run_part(input: &[String], mapper: impl Fn(String) -> String) -> u32 {
     input
-        .into_iter()
+        .iter()
+        .cloned()
         .map(mapper)
         .map(|line| {
             let first = line.chars().filter(char::is_ascii_digit).take(1);
@@ -31,3 +53,9 @@ fn replace_digits(line: String) -> String {
         .replace("eight", "e8t")
         .replace("nine", "n9e")
 }
+
+utils::examples! {
+    Day01,
+    part1: ["example" => 142],
+    part2: ["example2" => 281],
+}
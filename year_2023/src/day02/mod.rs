@@ -1,4 +1,4 @@
-use utils::{extract, extract_one, lines, read_input};
+use utils::{parse::parse_lines, scan, solution::Solution, ParseError};
 
 #[derive(Debug)]
 struct Run {
@@ -11,31 +11,36 @@ impl Run {
         self.reds <= reds && self.blues <= blues && self.greens <= greens
     }
 }
-impl From<String> for Run {
-    fn from(value: String) -> Self {
-        let blues = extract(&value, "\\d+ blue")
-            .into_iter()
-            .map(|val| extract_one(&val, "\\d+").parse::<usize>().unwrap())
-            .sum();
-        let reds = extract(&value, "\\d+ red")
-            .into_iter()
-            .map(|val| extract_one(&val, "\\d+").parse::<usize>().unwrap())
-            .sum();
-        let greens = extract(&value, "\\d+ green")
-            .into_iter()
-            .map(|val| extract_one(&val, "\\d+").parse::<usize>().unwrap())
-            .sum();
-
-        Self {
-            reds,
-            blues,
-            greens,
+impl Run {
+    fn parse(line: &str, run: &str) -> Result<Self, ParseError> {
+        let offset = |part: &str| part.as_ptr() as usize - line.as_ptr() as usize;
+        let mut result = Self {
+            reds: 0,
+            blues: 0,
+            greens: 0,
+        };
+        for cubes in run.split(", ") {
+            let at = |column: usize, message: String| {
+                ParseError::new(offset(cubes) + column, line, message)
+            };
+            let (count, color) = scan!(cubes, "{} {}", usize, String)
+                .map_err(|error| at(error.column, error.message))?;
+            match color.as_str() {
+                "red" => result.reds += count,
+                "blue" => result.blues += count,
+                "green" => result.greens += count,
+                _ => {
+                    let column = cubes.len() - color.len() + 1;
+                    return Err(at(column, "expected red, blue or green".to_string()));
+                }
+            }
         }
+        Ok(result)
     }
 }
 
 #[derive(Debug)]
-struct Game {
+pub struct Game {
     id: usize,
     runs: Vec<Run>,
 }
@@ -73,41 +78,52 @@ impl Game {
         }
     }
 }
-impl From<String> for Game {
-    fn from(value: String) -> Self {
-        let id = extract_one(&value, "\\d+").parse().unwrap();
-        let runs = value
-            .replace("Game \\d+:", "")
-            .split(';')
-            .map(String::from)
-            .map(Run::from)
-            .collect();
+impl TryFrom<&str> for Game {
+    type Error = ParseError;
+
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        let (id, _) = scan!(line, "Game {}: {}", usize, String)?;
+        let (_, runs) = line.split_once(": ").unwrap_or_default();
+        let runs = runs
+            .split("; ")
+            .map(|run| Run::parse(line, run))
+            .collect::<Result<Vec<Run>, ParseError>>()?;
 
-        Self { id, runs }
+        Ok(Self { id, runs })
     }
 }
 
-pub fn run() {
-    let games = lines(read_input!())
-        .into_iter()
-        .map(Game::from)
-        .collect::<Vec<_>>();
+pub struct Day02;
+impl Solution for Day02 {
+    type Input = Vec<Game>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    println!(
-        "Part1: {}",
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generate_games(input)
+    }
+    fn part1(games: &Self::Input) -> Self::Part1 {
         games
             .iter()
             .filter(|game| game.allowed(12, 14, 13))
             .map(|game| game.id)
             .sum::<usize>()
-    );
-
-    println!(
-        "Part2: {}",
+    }
+    fn part2(games: &Self::Input) -> Self::Part2 {
         games
             .iter()
             .map(|game| game.generate_max_run())
             .map(|run| run.greens * run.blues * run.reds)
             .sum::<usize>()
-    );
+    }
+}
+
+fn generate_games(input: &str) -> Result<Vec<Game>, ParseError> {
+    parse_lines(input, |line| Game::try_from(line))
+}
+
+utils::examples! {
+    Day02,
+    part1: ["example" => 8],
+    part2: ["example" => 2286],
 }
@@ -1,4 +1,4 @@
-use utils::{lines, read_input};
+use utils::{parse::field, solution::Solution, ParseError};
 
 #[derive(Debug, Clone, Copy)]
 struct Pos(usize, usize);
@@ -9,10 +9,10 @@ impl Pos {
 }
 
 #[derive(Debug)]
-struct SchemeNumber(u32, Vec<Pos>);
+pub struct SchemeNumber(u32, Vec<Pos>);
 impl SchemeNumber {
     fn is_next_to_symbol(&self, symbol: &SchemeSymbol) -> bool {
-        self.1.iter().any(|pos| pos.is_next_to(symbol.1))
+        self.1.iter().any(|pos| pos.is_next_to(symbol.0))
     }
     fn number(&self) -> u32 {
         self.0
@@ -20,38 +20,18 @@ impl SchemeNumber {
 }
 
 #[derive(Debug)]
-struct SchemeSymbol(char, Pos);
+pub struct SchemeSymbol(Pos);
 
-pub fn run() {
-    let mut numbers: Vec<SchemeNumber> = vec![];
-    let mut symbols: Vec<SchemeSymbol> = vec![];
-
-    let mut actual_number = String::new();
-    for (row, line) in lines(read_input!()).iter_mut().enumerate() {
-        line.push('.');
-        for (col, char) in line.chars().enumerate() {
-            if char.is_ascii_digit() {
-                actual_number.push_str(&char.to_string());
-            } else if !actual_number.is_empty() {
-                numbers.push(SchemeNumber(
-                    actual_number.parse().unwrap(),
-                    ((col - actual_number.len())..col)
-                        .map(|col| Pos(row, col))
-                        .collect(),
-                ));
-                actual_number = String::new();
-            }
+pub struct Day03;
+impl Solution for Day03 {
+    type Input = (Vec<SchemeNumber>, Vec<SchemeSymbol>);
+    type Part1 = u32;
+    type Part2 = u32;
 
-            if !char.is_ascii_digit() && char != '.' {
-                symbols.push(SchemeSymbol(char, Pos(row, col)))
-            }
-        }
-
-        actual_number = String::new();
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generate_schematic(input)
     }
-
-    println!(
-        "Part1: {:?}",
+    fn part1((numbers, symbols): &Self::Input) -> Self::Part1 {
         numbers
             .iter()
             .filter(|number| {
@@ -61,10 +41,8 @@ pub fn run() {
             })
             .map(|number| number.number())
             .sum::<u32>()
-    );
-
-    println!(
-        "Part2: {:?}",
+    }
+    fn part2((numbers, symbols): &Self::Input) -> Self::Part2 {
         symbols
             .iter()
             .map(|symbol| {
@@ -79,5 +57,38 @@ pub fn run() {
                 }
             })
             .sum::<u32>()
-    );
+    }
+}
+
+fn generate_schematic(input: &str) -> Result<(Vec<SchemeNumber>, Vec<SchemeSymbol>), ParseError> {
+    let mut numbers: Vec<SchemeNumber> = vec![];
+    let mut symbols: Vec<SchemeSymbol> = vec![];
+
+    for (row, line) in input.lines().enumerate() {
+        let mut actual_number = String::new();
+        for (col, char) in line.chars().chain(['.']).enumerate() {
+            if char.is_ascii_digit() {
+                actual_number.push(char);
+            } else if !actual_number.is_empty() {
+                let start = col - actual_number.len();
+                numbers.push(SchemeNumber(
+                    field(line, start + 1, &actual_number).map_err(|it| it.offset_lines(row))?,
+                    (start..col).map(|col| Pos(row, col)).collect(),
+                ));
+                actual_number = String::new();
+            }
+
+            if !char.is_ascii_digit() && char != '.' {
+                symbols.push(SchemeSymbol(Pos(row, col)))
+            }
+        }
+    }
+
+    Ok((numbers, symbols))
+}
+
+utils::examples! {
+    Day03,
+    part1: ["example" => 4361],
+    part2: ["example" => 467835],
 }
@@ -1,7 +1,12 @@
-use utils::{extract, extract_one, lines, read_input};
+use utils::{
+    parse::{field, parse_lines, uints},
+    scan,
+    solution::Solution,
+    ParseError,
+};
 
 #[derive(Debug, Clone)]
-struct Card {
+pub struct Card {
     id: usize,
     winning_numbers: Vec<u32>,
     numbers: Vec<u32>,
@@ -22,42 +27,51 @@ impl Card {
             .count() as u32
     }
 }
-impl From<String> for Card {
-    fn from(value: String) -> Self {
-        let id = extract_one(&value, "\\d+").parse().unwrap();
-        let value = value.split(':').collect::<Vec<_>>()[1];
-        let inputs = value.split('|').collect::<Vec<_>>();
-        let winning_numbers = extract(inputs[0], "\\d+")
-            .into_iter()
-            .map(|it| it.parse::<u32>().unwrap())
-            .collect();
-        let numbers = extract(inputs[1], "\\d+")
-            .into_iter()
-            .map(|it| it.parse::<u32>().unwrap())
-            .collect();
+impl TryFrom<&str> for Card {
+    type Error = ParseError;
 
-        Self {
-            id,
-            winning_numbers,
-            numbers,
-        }
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        let (id, winning_numbers, numbers) =
+            scan!(line, "Card{}: {} | {}", String, String, String)?;
+        let numbers_in = |part: &str| {
+            let offset = line.rfind(part).unwrap_or_default();
+            uints::<u32>(part)
+                .map_err(|error| ParseError::new(offset + error.column, line, error.message))
+        };
+
+        Ok(Self {
+            id: field(line, 5 + id.len() - id.trim_start().len(), id.trim_start())?,
+            winning_numbers: numbers_in(&winning_numbers)?,
+            numbers: numbers_in(&numbers)?,
+        })
     }
 }
 
-pub fn run() {
-    let cards = lines(read_input!())
-        .into_iter()
-        .map(Card::from)
-        .collect::<Vec<_>>();
+pub struct Day04;
+impl Solution for Day04 {
+    type Input = Vec<Card>;
+    type Part1 = u32;
+    type Part2 = u32;
 
-    println!("Part1: {}", part1(&cards));
-    println!("Part2: {}", part2(&cards));
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        generate_cards(input)
+    }
+    fn part1(cards: &Self::Input) -> Self::Part1 {
+        points(cards)
+    }
+    fn part2(cards: &Self::Input) -> Self::Part2 {
+        scratchcards(cards)
+    }
 }
 
-fn part1(cards: &[Card]) -> u32 {
+fn generate_cards(input: &str) -> Result<Vec<Card>, ParseError> {
+    parse_lines(input, |line| Card::try_from(line))
+}
+
+fn points(cards: &[Card]) -> u32 {
     cards.iter().map(|it| it.points()).sum::<u32>()
 }
-fn part2(cards: &[Card]) -> u32 {
+fn scratchcards(cards: &[Card]) -> u32 {
     let mut count = 0;
     let mut stack = cards.iter().map(Clone::clone).collect::<Vec<_>>();
     while let Some(actual_card) = stack.pop() {
@@ -70,3 +84,9 @@ fn part2(cards: &[Card]) -> u32 {
     }
     count
 }
+
+utils::examples! {
+    Day04,
+    part1: ["example" => 13],
+    part2: ["example" => 30],
+}
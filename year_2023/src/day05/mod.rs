@@ -1,153 +1,88 @@
-use std::collections::HashSet;
+use utils::{
+    interval::{Interval, OffsetMap, RangeSet},
+    parse::{parse_lines, parse_paragraphs, uints},
+    solution::Solution,
+    ParseError,
+};
 
-use utils::{extract, extract_one, read_input};
-
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-struct Range {
-    from: i64,
-    to: i64,
-    offset: i64,
-}
-impl Range {
-    fn intersect(&self, r2: &Range) -> Option<Range> {
-        if r2.from > self.to || self.from > r2.to {
-            None
-        } else {
-            Some(Range {
-                from: self.from.max(r2.from) + r2.offset,
-                to: self.to.min(r2.to) + r2.offset,
-                offset: 0,
-            })
-        }
+fn range(line: &str) -> Result<(Interval<i64>, i64), ParseError> {
+    match uints::<i64>(line)?[..] {
+        [destination, from, length] => match from.checked_add(length) {
+            Some(to) => Ok((Interval::new(from, to), destination - from)),
+            None => Err(ParseError::new(1, line, "range is too large")),
+        },
+        _ => Err(ParseError::new(1, line, "expected 3 numbers")),
     }
+}
 
-    fn intersect_many(&self, ranges: &[Range]) -> HashSet<Range> {
-        let min = ranges.iter().map(|it| it.from).min().unwrap_or(self.from);
-        let max = ranges.iter().map(|it| it.to).max().unwrap_or(self.to);
-
-        let mut found_ranges = ranges
-            .iter()
-            .filter_map(|it| self.intersect(it))
-            .collect::<HashSet<_>>();
+fn mapper(paragraph: &str) -> Result<OffsetMap<i64>, ParseError> {
+    let (_, ranges) = paragraph.split_once('\n').unwrap_or((paragraph, ""));
+    let ranges = parse_lines(ranges, range).map_err(|error| error.offset_lines(1))?;
 
-        if min <= self.to && min >= self.from {
-            found_ranges.insert(Range {
-                from: self.from,
-                to: min - 1,
-                offset: 0,
-            });
-        }
-        if max >= self.from && max <= self.to {
-            found_ranges.insert(Range {
-                from: max + 1,
-                to: self.to,
-                offset: 0,
-            });
-        }
-        if max < self.from || min > self.to {
-            found_ranges.insert(Range {
-                from: self.from,
-                to: self.to,
-                offset: 0,
-            });
-        }
-        found_ranges
-    }
+    let mut mapper = OffsetMap::new();
+    ranges
+        .into_iter()
+        .for_each(|(interval, offset)| mapper.insert(interval, offset));
+    Ok(mapper)
 }
-impl From<String> for Range {
-    fn from(value: String) -> Self {
-        let numbers = extract(&value, "\\d+");
-        let destination: i64 = numbers[0].parse().unwrap();
-        let from: i64 = numbers[1].parse().unwrap();
-        let length: i64 = numbers[2].parse().unwrap();
-        Self {
-            from,
-            to: from + length - 1,
-            offset: destination - from,
+
+pub struct Day05;
+impl Solution for Day05 {
+    type Input = (Vec<i64>, OffsetMap<i64>);
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (seeds, maps) = input.split_once('\n').unwrap_or((input, ""));
+        let seeds = uints::<i64>(seeds)?;
+        let pairs = seeds.chunks(2);
+        if seeds.is_empty()
+            || pairs
+                .clone()
+                .any(|pair| pair.len() != 2 || pair[0].checked_add(pair[1]).is_none())
+        {
+            return Err(ParseError::new(
+                1,
+                input.lines().next().unwrap_or_default(),
+                "expected pairs of seed ranges",
+            ));
         }
-    }
-}
 
-#[derive(Debug)]
-struct Mapper {
-    ranges: Vec<Range>,
-}
-impl Mapper {
-    fn find_ranges(&self, sources: Vec<Range>) -> Vec<Range> {
-        sources
+        let almanac = parse_paragraphs(maps, mapper)
+            .map_err(|error| error.offset_lines(1))?
             .iter()
-            .flat_map(|range| range.intersect_many(&self.ranges))
-            .collect()
+            .fold(OffsetMap::new(), |almanac, mapper| almanac.compose(mapper));
+
+        Ok((seeds, almanac))
     }
-}
-impl From<String> for Mapper {
-    fn from(value: String) -> Self {
-        let category_string = extract_one(&value, ".* map:");
-        let ranges = value
-            .replace(&category_string, "")
-            .split('\n')
-            .filter(|it| !it.is_empty())
-            .map(|it| Range::from(it.to_string()))
-            .collect();
-        Mapper { ranges }
+    fn part1((seeds, almanac): &Self::Input) -> Self::Part1 {
+        runner(gen_range_1(seeds), almanac)
+    }
+    fn part2((seeds, almanac): &Self::Input) -> Self::Part2 {
+        runner(gen_range_2(seeds), almanac)
     }
 }
 
-pub fn run() {
-    println!("Part1: {}", runner(gen_range_1));
-    println!("Part2: {}", runner(gen_range_2))
-}
-
-fn runner(gen_range: fn(Vec<i64>) -> Vec<Range>) -> i64 {
-    let lines = read_input!()
-        .split("\n\n")
-        .map(|it| it.to_owned())
-        .collect::<Vec<_>>();
-
-    let seed_ranges = gen_range(
-        extract(&lines[0], "\\d+")
-            .into_iter()
-            .map(|it| it.parse().unwrap())
-            .collect::<Vec<_>>(),
-    );
-
-    let mappers = lines
-        .into_iter()
-        .skip(1)
-        .map(Mapper::from)
-        .collect::<Vec<_>>();
-
-    mappers
-        .iter()
-        .fold(seed_ranges, |sources, mapper| mapper.find_ranges(sources))
-        .into_iter()
-        .map(|it| it.from)
-        .min()
-        .unwrap()
+fn runner(seeds: RangeSet<i64>, almanac: &OffsetMap<i64>) -> i64 {
+    almanac.apply(&seeds).min().unwrap()
 }
 
-fn gen_range_1(seeds: Vec<i64>) -> Vec<Range> {
+fn gen_range_1(seeds: &[i64]) -> RangeSet<i64> {
     seeds
         .iter()
-        .map(|value| Range {
-            from: *value,
-            to: *value,
-            offset: 0,
-        })
+        .map(|&value| Interval::from(value..=value))
         .collect()
 }
 
-fn gen_range_2(seeds: Vec<i64>) -> Vec<Range> {
+fn gen_range_2(seeds: &[i64]) -> RangeSet<i64> {
     seeds
         .chunks(2)
-        .map(|values| {
-            let from = values[0];
-            let lenght = values[1];
-            Range {
-                from,
-                to: from + lenght - 1,
-                offset: 0,
-            }
-        })
+        .map(|values| Interval::new(values[0], values[0] + values[1]))
         .collect()
 }
+
+utils::examples! {
+    Day05,
+    part1: ["example" => 35],
+    part2: ["example" => 46],
+}
@@ -1,32 +1,76 @@
-use utils::{extract, lines, read_input, replace};
+use utils::{
+    parse::{field, uints},
+    solution::Solution,
+    ParseError,
+};
 
-pub fn run() {
-    let input = replace(&read_input!(), "\\w+:", "");
-
-    println!("Part1: {:?}", runner(input.clone()));
-    println!("Part2: {:?}", runner(input.replace(' ', "")));
+pub struct Races {
+    times: Vec<usize>,
+    distances: Vec<usize>,
+    time: usize,
+    distance: usize,
 }
 
-fn runner(input: String) -> usize {
-    let lines = lines(input)
-        .into_iter()
-        .map(|it| {
-            extract(&it, "\\d+")
-                .into_iter()
-                .map(|n| n.parse::<usize>().unwrap())
-                .collect::<Vec<_>>()
-        })
-        .collect::<Vec<_>>();
+pub struct Day06;
+impl Solution for Day06 {
+    type Input = Races;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    lines[0]
-        .clone()
-        .into_iter()
-        .zip(lines[1].clone())
-        .map(|(time, distance)| {
-            (1..time)
-                .map(|it| it * (time - it))
-                .filter(|it| it > &distance)
-                .count()
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let lines = input.lines().collect::<Vec<_>>();
+        let numbers = |row: usize, label: &str| {
+            let line = lines.get(row).copied().unwrap_or_default();
+            let Some(text) = line.strip_prefix(label) else {
+                return Err(
+                    ParseError::new(1, line, format!("expected {label:?}")).offset_lines(row)
+                );
+            };
+            let single = text.replace(' ', "");
+            uints::<usize>(text)
+                .and_then(|numbers| Ok((numbers, field(text, 1, &single)?)))
+                .map_err(|error| {
+                    ParseError::new(label.len() + error.column, line, error.message)
+                        .offset_lines(row)
+                })
+        };
+        let (times, time) = numbers(0, "Time:")?;
+        let (distances, distance) = numbers(1, "Distance:")?;
+        if times.len() != distances.len() {
+            return Err(
+                ParseError::new(1, lines[1], "expected as many distances as times").offset_lines(1),
+            );
+        }
+
+        Ok(Races {
+            times,
+            distances,
+            time,
+            distance,
         })
-        .product::<usize>()
+    }
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        input
+            .times
+            .iter()
+            .zip(&input.distances)
+            .map(|(&time, &distance)| winning_ways(time, distance))
+            .product()
+    }
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        winning_ways(input.time, input.distance)
+    }
+}
+
+fn winning_ways(time: usize, distance: usize) -> usize {
+    (1..time)
+        .map(|it| it * (time - it))
+        .filter(|it| it > &distance)
+        .count()
+}
+
+utils::examples! {
+    Day06,
+    part1: ["example" => 288],
+    part2: ["example" => 71503],
 }
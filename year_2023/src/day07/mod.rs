@@ -1,9 +1,9 @@
 use std::{cmp::Ordering, collections::HashMap};
 
-use utils::{lines, read_input};
+use utils::{parse::parse_lines, scan, solution::Solution, ParseError};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
-enum Card {
+pub enum Card {
     A,
     K,
     Q,
@@ -11,15 +11,18 @@ enum Card {
     T,
     Number(u32),
 }
-impl From<char> for Card {
-    fn from(value: char) -> Self {
+impl TryFrom<char> for Card {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
-            'A' => Self::A,
-            'K' => Self::K,
-            'Q' => Self::Q,
-            'J' => Self::J,
-            'T' => Self::T,
-            char => Self::Number(char.to_string().parse().unwrap()),
+            'A' => Ok(Self::A),
+            'K' => Ok(Self::K),
+            'Q' => Ok(Self::Q),
+            'J' => Ok(Self::J),
+            'T' => Ok(Self::T),
+            '2'..='9' => Ok(Self::Number(value as u32 - '0' as u32)),
+            _ => Err(format!("{value:?} is not a card")),
         }
     }
 }
@@ -147,20 +150,42 @@ fn rule(
         .then_some(return_type)
 }
 
-pub fn run() {
-    println!("Part1: {:?}", runner(Rule::Default));
-    println!("Part2: {:?}", runner(Rule::Jokers));
-}
+pub struct Day07;
+impl Solution for Day07 {
+    type Input = Vec<(Vec<Card>, u32)>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, |line| {
+            let (cards, bid) = scan!(line, "{} {}", String, u32)?;
+            let cards = cards
+                .chars()
+                .enumerate()
+                .map(|(column, card)| {
+                    Card::try_from(card)
+                        .map_err(|message| ParseError::new(column + 1, line, message))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            if cards.len() != 5 {
+                return Err(ParseError::new(1, line, "a hand has exactly 5 cards"));
+            }
 
-fn runner(rule: Rule) -> u32 {
-    let mut hands = lines(read_input!())
-        .into_iter()
-        .map(|value| {
-            let data = value.split(' ').collect::<Vec<_>>();
-            let cards = data[0].chars().map(Card::from).collect::<Vec<_>>();
-            let bid = data[1].parse().unwrap();
-            Hand::new(cards, rule, bid)
+            Ok((cards, bid))
         })
+    }
+    fn part1(lines: &Self::Input) -> Self::Part1 {
+        runner(lines, Rule::Default)
+    }
+    fn part2(lines: &Self::Input) -> Self::Part2 {
+        runner(lines, Rule::Jokers)
+    }
+}
+
+fn runner(lines: &[(Vec<Card>, u32)], rule: Rule) -> u32 {
+    let mut hands = lines
+        .iter()
+        .map(|(cards, bid)| Hand::new(cards.clone(), rule, *bid))
         .collect::<Vec<_>>();
     hands.sort_by(|a, b| b.cmp(a));
 
@@ -170,3 +195,9 @@ fn runner(rule: Rule) -> u32 {
         .map(|(idx, hand)| hand.bid * (idx + 1) as u32)
         .sum::<u32>()
 }
+
+utils::examples! {
+    Day07,
+    part1: ["example" => 6440],
+    part2: ["example" => 5905],
+}
@@ -1,35 +1,57 @@
 use std::collections::HashMap;
 
-use utils::{extract, lines, read_input};
+use utils::{
+    math,
+    parse::{paragraphs, parse_lines},
+    scan,
+    solution::Solution,
+    ParseError,
+};
 
 type StringMapping = HashMap<String, (String, String)>;
 
 #[derive(Clone, Copy, Debug)]
-enum Movement {
+pub enum Movement {
     Left,
     Right,
 }
-impl From<char> for Movement {
-    fn from(value: char) -> Self {
+impl TryFrom<char> for Movement {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
-            'L' => Self::Left,
-            'R' => Self::Right,
-            _ => panic!("invalid input"),
+            'L' => Ok(Self::Left),
+            'R' => Ok(Self::Right),
+            _ => Err(format!("expected L or R, found {value:?}")),
         }
     }
 }
 
 #[derive(Clone, Debug)]
-struct Movements {
+pub struct Movements {
     movements: Vec<Movement>,
     actual: usize,
 }
-impl From<String> for Movements {
-    fn from(value: String) -> Self {
-        Self {
-            movements: value.chars().map(Movement::from).collect(),
-            actual: 0,
+impl TryFrom<&str> for Movements {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let movements = value
+            .chars()
+            .enumerate()
+            .map(|(column, movement)| {
+                Movement::try_from(movement)
+                    .map_err(|message| ParseError::new(column + 1, value, message))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if movements.is_empty() {
+            return Err(ParseError::new(1, value, "expected at least one movement"));
         }
+
+        Ok(Self {
+            movements,
+            actual: 0,
+        })
     }
 }
 impl Iterator for Movements {
@@ -43,7 +65,7 @@ impl Iterator for Movements {
 }
 
 #[derive(Debug)]
-struct Mappings {
+pub struct Mappings {
     map: StringMapping,
 }
 impl Mappings {
@@ -54,106 +76,185 @@ impl Mappings {
         }
     }
 
-    fn get_steps_by_ending(
-        &mut self,
+    fn endings(
+        &self,
         mut movements: Movements,
         starting: String,
         end_condition: fn(&String) -> bool,
-    ) -> Vec<(u64, String)> {
-        let mut endings = Vec::new();
-        let mut steps_by_ending = Vec::new();
+    ) -> Endings {
+        let mut seen: HashMap<(usize, String), u64> = HashMap::new();
+        let mut hits = vec![];
         let mut count = 0;
-        let mut actual = starting.clone();
+        let mut actual = starting;
 
         loop {
             actual = self.next(movements.next().unwrap(), &actual);
             count += 1;
-            if endings.contains(&(movements.actual, actual.clone())) {
-                break;
+            let state = (movements.actual, actual.clone());
+            if let Some(&start) = seen.get(&state) {
+                let (transient, hits) = hits.into_iter().partition(|&hit| hit < start);
+                return Endings {
+                    transient,
+                    hits,
+                    length: count - start,
+                };
             }
             if end_condition(&actual) {
-                endings.push((movements.actual, actual.clone()));
-                steps_by_ending.push((count, actual.clone()))
+                hits.push(count);
             }
+            seen.insert(state, count);
         }
+    }
+}
 
-        steps_by_ending
+/// The steps at which a ghost stands on an ending: each of `hits` repeats every
+/// `length` steps, while the `transient` ones come before the cycle and never again.
+#[derive(Debug)]
+struct Endings {
+    transient: Vec<u64>,
+    hits: Vec<u64>,
+    length: u64,
+}
+impl Endings {
+    fn contains(&self, step: u64) -> bool {
+        self.transient.contains(&step)
+            || self
+                .hits
+                .iter()
+                .any(|&hit| step >= hit && (step - hit).is_multiple_of(self.length))
     }
 }
-impl From<Vec<String>> for Mappings {
-    fn from(value: Vec<String>) -> Self {
-        let mut map: StringMapping = HashMap::new();
+impl TryFrom<&str> for Mappings {
+    type Error = ParseError;
 
-        value.into_iter().for_each(|line| {
-            let key = line.split(" = ").collect::<Vec<_>>()[0];
-            let value = line.split(" = ").collect::<Vec<_>>()[1];
-            let elements = extract(value, "\\w+");
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let nodes = parse_lines(value, |line| {
+            scan!(line, "{} = ({}, {})", String, String, String)
+        })?;
+        let map: StringMapping = nodes
+            .iter()
+            .map(|(key, left, right)| (key.clone(), (left.clone(), right.clone())))
+            .collect();
 
-            map.insert(key.to_string(), (elements[0].clone(), elements[1].clone()));
-        });
+        let lines = value
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty());
+        for ((row, line), (_, left, right)) in lines.zip(&nodes) {
+            for target in [left, right] {
+                if !map.contains_key(target) {
+                    let column = line.rfind(target.as_str()).unwrap_or_default() + 1;
+                    let message = format!("unknown node {target:?}");
+                    return Err(ParseError::new(column, line, message).offset_lines(row));
+                }
+            }
+        }
 
-        Self { map }
+        Ok(Self { map })
     }
 }
 
-pub fn run() {
-    println!(
-        "Part1: {}",
+pub struct Day08;
+impl Solution for Day08 {
+    type Input = (Movements, Mappings);
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let [movements, network] = paragraphs(input)[..] else {
+            return Err(ParseError::new(
+                1,
+                input,
+                "expected the movements and the network",
+            ));
+        };
+        let offset = network.as_ptr() as usize - input.as_ptr() as usize;
+        let line = input[..offset].matches('\n').count();
+
+        Ok((
+            Movements::try_from(movements)?,
+            Mappings::try_from(network).map_err(|error| error.offset_lines(line))?,
+        ))
+    }
+    fn part1((movements, mappings): &Self::Input) -> Self::Part1 {
         runner(
+            movements,
+            mappings,
             |map| vec![map.keys().find(|it| *it == "AAA").unwrap().to_string()],
-            |actual| actual == "ZZZ"
+            |actual| actual == "ZZZ",
         )
-    );
-    println!(
-        "Part2: {}",
+    }
+    fn part2((movements, mappings): &Self::Input) -> Self::Part2 {
         runner(
-            |map| map
-                .keys()
-                .filter(|it| it.ends_with('A'))
-                .cloned()
-                .collect::<Vec<_>>(),
-            |actual| actual.ends_with('Z')
+            movements,
+            mappings,
+            |map| {
+                map.keys()
+                    .filter(|it| it.ends_with('A'))
+                    .cloned()
+                    .collect::<Vec<_>>()
+            },
+            |actual| actual.ends_with('Z'),
         )
-    );
+    }
 }
 
-fn runner(elements: fn(&StringMapping) -> Vec<String>, end_condition: fn(&String) -> bool) -> u64 {
-    let lines = lines(read_input!());
-    let movements = Movements::from(lines[0].clone());
-    let mut mappings = Mappings::from(lines.into_iter().skip(1).collect::<Vec<_>>());
+fn runner(
+    movements: &Movements,
+    mappings: &Mappings,
+    elements: fn(&StringMapping) -> Vec<String>,
+    end_condition: fn(&String) -> bool,
+) -> u64 {
+    let ghosts = elements(&mappings.map)
+        .into_iter()
+        .map(|start| mappings.endings(movements.clone(), start, end_condition))
+        .collect::<Vec<_>>();
+    let transient = ghosts
+        .iter()
+        .flat_map(|ghost| ghost.transient.iter().copied())
+        .filter(|&step| ghosts.iter().all(|ghost| ghost.contains(step)))
+        .min();
 
-    let acts = elements(&mappings.map);
-
-    let mut total = vec![];
-    for x in acts {
-        total.append(&mut mappings.get_steps_by_ending(movements.clone(), x, end_condition))
+    let mut combinations: Vec<Vec<(u64, u64)>> = vec![vec![]];
+    for Endings { hits, length, .. } in ghosts {
+        combinations = combinations
+            .into_iter()
+            .flat_map(|combination| {
+                hits.iter().map(move |&hit| {
+                    let mut combination = combination.clone();
+                    combination.push((hit, length));
+                    combination
+                })
+            })
+            .collect();
     }
 
-    vector_lcm(&total.into_iter().map(|(v, _)| v).collect::<Vec<_>>())
+    combinations
+        .iter()
+        .filter_map(|combination| first_common_step(combination))
+        .chain(transient)
+        .min()
+        .unwrap()
 }
 
-fn vector_lcm(numbers: &[u64]) -> u64 {
-    let mut result = 1;
+fn first_common_step(hits: &[(u64, u64)]) -> Option<u64> {
+    let congruences: Vec<(i64, i64)> = hits
+        .iter()
+        .map(|&(hit, length)| ((hit % length) as i64, length as i64))
+        .collect();
+    let (residue, modulus) = math::crt(&congruences)?;
 
-    for &num in numbers {
-        result = lcm(result, num);
-    }
-    result
-}
-
-fn lcm(a: u64, b: u64) -> u64 {
-    if a == 0 || b == 0 {
-        0
+    let (residue, modulus) = (residue as u64, modulus as u64);
+    let earliest = hits.iter().map(|&(hit, _)| hit).max()?;
+    if residue >= earliest {
+        Some(residue)
     } else {
-        (a * b) / gcd(a, b)
+        Some(residue + (earliest - residue).div_ceil(modulus) * modulus)
     }
 }
 
-fn gcd(mut a: u64, mut b: u64) -> u64 {
-    while b != 0 {
-        let temp = b;
-        b = a % b;
-        a = temp;
-    }
-    a
+utils::examples! {
+    Day08,
+    part1: ["example3" => 2, "example4" => 6],
+    part2: ["example" => 6, "example2" => 1],
 }
@@ -1,17 +1,30 @@
-use utils::{extract, lines, read_input};
+use utils::{
+    parse::{field, parse_lines},
+    solution::Solution,
+    ParseError,
+};
 
 #[derive(Clone, Debug)]
-struct Sequence {
+pub struct Sequence {
     numbers: Vec<i32>,
 }
-impl From<String> for Sequence {
-    fn from(value: String) -> Self {
-        Self {
-            numbers: extract(&value, "-?\\d+")
-                .into_iter()
-                .map(|it| it.parse::<i32>().unwrap())
-                .collect(),
+impl TryFrom<&str> for Sequence {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let numbers = value
+            .split(' ')
+            .filter(|it| !it.is_empty())
+            .map(|number| {
+                let column = number.as_ptr() as usize - value.as_ptr() as usize + 1;
+                field::<i32>(value, column, number)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if numbers.is_empty() {
+            return Err(ParseError::new(1, value, "expected at least one number"));
         }
+
+        Ok(Self { numbers })
     }
 }
 impl Sequence {
@@ -56,21 +69,28 @@ impl Sequence {
     }
 }
 
-pub fn run() {
-    println!(
-        "Part1: {:?}",
-        lines(read_input!())
-            .into_iter()
-            .map(Sequence::from)
-            .map(|it| it.predict())
-            .sum::<i32>()
-    );
-    println!(
-        "Part2: {:?}",
-        lines(read_input!())
-            .into_iter()
-            .map(Sequence::from)
+pub struct Day09;
+impl Solution for Day09 {
+    type Input = Vec<Sequence>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, |line| Sequence::try_from(line))
+    }
+    fn part1(sequences: &Self::Input) -> Self::Part1 {
+        sequences.iter().map(|it| it.predict()).sum::<i32>()
+    }
+    fn part2(sequences: &Self::Input) -> Self::Part2 {
+        sequences
+            .iter()
             .map(|it| it.predict_backward())
             .sum::<i32>()
-    );
+    }
+}
+
+utils::examples! {
+    Day09,
+    part1: ["example" => 114],
+    part2: ["example" => 2],
 }
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use utils::{lines, read_input};
+use utils::{solution::Solution, ParseError};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 struct Pos {
@@ -39,18 +39,20 @@ enum PipeType {
     Ground,
     Animal,
 }
-impl From<char> for PipeType {
-    fn from(value: char) -> Self {
+impl TryFrom<char> for PipeType {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
-            'S' => Self::Animal,
-            '.' => Self::Ground,
-            '|' => Self::Vertical,
-            '-' => Self::Horizontal,
-            'L' => Self::L,
-            'J' => Self::J,
-            '7' => Self::Seven,
-            'F' => Self::F,
-            _ => panic!("Invalid input"),
+            'S' => Ok(Self::Animal),
+            '.' => Ok(Self::Ground),
+            '|' => Ok(Self::Vertical),
+            '-' => Ok(Self::Horizontal),
+            'L' => Ok(Self::L),
+            'J' => Ok(Self::J),
+            '7' => Ok(Self::Seven),
+            'F' => Ok(Self::F),
+            _ => Err(format!("{value:?} is not a pipe")),
         }
     }
 }
@@ -60,15 +62,17 @@ struct Pipe {
     pipe_type: PipeType,
     pos: Pos,
 }
-impl From<(i32, i32, char)> for Pipe {
-    fn from(value: (i32, i32, char)) -> Self {
-        Self {
-            pipe_type: PipeType::from(value.2),
+impl TryFrom<(i32, i32, char)> for Pipe {
+    type Error = String;
+
+    fn try_from(value: (i32, i32, char)) -> Result<Self, Self::Error> {
+        Ok(Self {
+            pipe_type: PipeType::try_from(value.2)?,
             pos: Pos {
                 x: value.0,
                 y: value.1,
             },
-        }
+        })
     }
 }
 impl Pipe {
@@ -98,7 +102,7 @@ impl Pipe {
 }
 
 #[derive(Debug)]
-struct Map {
+pub struct Map {
     pipes: HashMap<Pos, Pipe>,
 }
 impl Map {
@@ -145,33 +149,63 @@ impl Map {
         sum.abs() / 2
     }
 }
-impl From<Vec<String>> for Map {
-    fn from(lines: Vec<String>) -> Self {
-        Self {
-            pipes: lines
-                .into_iter()
-                .enumerate()
-                .flat_map(|(y, line)| {
-                    line.chars()
-                        .enumerate()
-                        .map(|(x, char)| {
-                            let x: i32 = x as i32;
-                            let y: i32 = y as i32;
-                            (Pos::new(x, y), Pipe::from((x, y, char)))
-                        })
-                        .collect::<Vec<_>>()
-                })
-                .collect(),
+impl TryFrom<&str> for Map {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let mut pipes = HashMap::new();
+        for (y, line) in input.lines().enumerate() {
+            for (x, char) in line.chars().enumerate() {
+                let pipe = Pipe::try_from((x as i32, y as i32, char))
+                    .map_err(|message| ParseError::new(x + 1, line, message).offset_lines(y))?;
+                pipes.insert(pipe.pos.clone(), pipe);
+            }
         }
+
+        let animals = pipes
+            .values()
+            .filter(|it| it.pipe_type == PipeType::Animal)
+            .count();
+        if animals != 1 {
+            return Err(ParseError::new(1, input, "expected exactly one animal"));
+        }
+        let map = Self { pipes };
+        let directions = [
+            Direction::Up,
+            Direction::Right,
+            Direction::Down,
+            Direction::Left,
+        ];
+        if !directions
+            .into_iter()
+            .any(|dir| map.find_boundary_by_dir(dir).is_some())
+        {
+            return Err(ParseError::new(1, input, "the animal is not on a loop"));
+        }
+
+        Ok(map)
     }
 }
 
-pub fn run() {
-    let map = Map::from(lines(read_input!()));
-    println!("Part1: {}", map.boundary().len() / 2);
+pub struct Day10;
+impl Solution for Day10 {
+    type Input = Map;
+    type Part1 = usize;
+    type Part2 = i32;
 
-    println!(
-        "Part2: {}",
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Map::try_from(input)
+    }
+    fn part1(map: &Self::Input) -> Self::Part1 {
+        map.boundary().len() / 2
+    }
+    fn part2(map: &Self::Input) -> Self::Part2 {
         map.area() - (map.boundary().len() as i32) / 2 + 1
-    )
+    }
+}
+
+utils::examples! {
+    Day10,
+    part1: ["example" => 8],
+    part2: ["example2" => 4],
 }
@@ -1,6 +1,6 @@
-use utils::{lines, read_input};
+use utils::{solution::Solution, ParseError};
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 struct Pos {
     x: i64,
     y: i64,
@@ -11,7 +11,7 @@ impl Pos {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 struct Galaxy {
     pos: Pos,
 }
@@ -21,8 +21,8 @@ impl Galaxy {
     }
 }
 
-#[derive(Debug)]
-struct Universe {
+#[derive(Clone, Debug)]
+pub struct Universe {
     galaxies: Vec<Galaxy>,
 }
 impl Universe {
@@ -52,36 +52,55 @@ impl Universe {
         });
     }
 }
-impl From<Vec<String>> for Universe {
-    fn from(lines: Vec<String>) -> Self {
-        Self {
-            galaxies: lines
-                .into_iter()
-                .enumerate()
-                .flat_map(|(y, line)| {
-                    line.chars()
-                        .enumerate()
-                        .filter(|(_, c)| c == &'#')
-                        .map(|(x, _)| Galaxy {
-                            pos: Pos {
-                                x: x as i64,
-                                y: y as i64,
-                            },
-                        })
-                        .collect::<Vec<_>>()
-                })
-                .collect(),
+impl TryFrom<&str> for Universe {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let mut galaxies = vec![];
+        for (y, line) in input.lines().enumerate() {
+            for (x, char) in line.chars().enumerate() {
+                match char {
+                    '#' => galaxies.push(Galaxy {
+                        pos: Pos {
+                            x: x as i64,
+                            y: y as i64,
+                        },
+                    }),
+                    '.' => {}
+                    _ => {
+                        let message = format!("expected '#' or '.', found {char:?}");
+                        return Err(ParseError::new(x + 1, line, message).offset_lines(y));
+                    }
+                }
+            }
+        }
+        if galaxies.is_empty() {
+            return Err(ParseError::new(1, input, "expected at least one galaxy"));
         }
+
+        Ok(Self { galaxies })
     }
 }
 
-pub fn run() {
-    println!("Part1: {:?}", runner(2));
-    println!("Part2: {:?}", runner(1000000));
+pub struct Day11;
+impl Solution for Day11 {
+    type Input = Universe;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Universe::try_from(input)
+    }
+    fn part1(universe: &Self::Input) -> Self::Part1 {
+        runner(universe, 2)
+    }
+    fn part2(universe: &Self::Input) -> Self::Part2 {
+        runner(universe, 1000000)
+    }
 }
 
-fn runner(age: i64) -> i64 {
-    let mut uni = Universe::from(lines(read_input!()));
+fn runner(universe: &Universe, age: i64) -> i64 {
+    let mut uni = universe.clone();
     uni.expand(age);
     uni.galaxies
         .iter()
@@ -95,3 +114,9 @@ fn runner(age: i64) -> i64 {
         })
         .sum::<i64>()
 }
+
+utils::examples! {
+    Day11,
+    part1: ["example" => 374],
+    part2: ["example" => 82000210],
+}
@@ -1,19 +1,14 @@
 use std::collections::HashMap;
 
-use utils::{extract, lines, read_input};
-
-fn replace(vec: &[char], skip: usize, take: usize, char: char) -> Vec<char> {
-    let mut new_vec = vec.to_vec();
-    new_vec
-        .iter_mut()
-        .skip(skip)
-        .take(take)
-        .for_each(|it| *it = char);
-    new_vec
-}
+use utils::{
+    parse::{field, parse_lines},
+    scan,
+    solution::Solution,
+    ParseError,
+};
 
 #[derive(Debug)]
-struct Case {
+pub struct Case {
     value: Vec<char>,
     elements: Vec<usize>,
     actual: usize,
@@ -48,16 +43,8 @@ impl Case {
                         z
                     }
                 }
-                Some('#') => {
-                    if self.can_fit(char_idx, *element) {
-                        self.combinations_for_indexes(
-                            cache,
-                            char_idx + element + 1,
-                            element_idx + 1,
-                        )
-                    } else {
-                        0
-                    }
+                Some('#') if self.can_fit(char_idx, *element) => {
+                    self.combinations_for_indexes(cache, char_idx + element + 1, element_idx + 1)
                 }
                 _ => 0,
             }
@@ -93,34 +80,64 @@ impl Case {
         !any_dot && !hash_after && !overflow
     }
 }
-impl From<String> for Case {
-    fn from(line: String) -> Self {
-        Self {
-            value: line.split(' ').collect::<Vec<_>>()[0].chars().collect(),
-            elements: extract(line.split(' ').collect::<Vec<_>>()[1], "\\d+")
-                .into_iter()
-                .map(|it| it.parse::<usize>().unwrap())
-                .collect(),
-            actual: 0,
+impl TryFrom<&str> for Case {
+    type Error = ParseError;
+
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        let (value, elements) = scan!(line, "{} {}", String, String)?;
+        if let Some(column) = value.find(|it| !matches!(it, '.' | '#' | '?')) {
+            return Err(ParseError::new(
+                column + 1,
+                line,
+                "expected '.', '#' or '?'",
+            ));
         }
+        let elements = elements
+            .split(',')
+            .scan(value.len() + 2, |column, element| {
+                let start = *column;
+                *column += element.len() + 1;
+                Some(field::<usize>(line, start, element).and_then(|size| {
+                    if (1..=value.len()).contains(&size) {
+                        Ok(size)
+                    } else {
+                        let message = format!("group size must be between 1 and {}", value.len());
+                        Err(ParseError::new(start, line, message))
+                    }
+                }))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            value: value.chars().collect(),
+            elements,
+            actual: 0,
+        })
     }
 }
 
-pub fn run() {
-    println!(
-        "Part1: {}",
-        lines(read_input!())
-            .into_iter()
-            .map(Case::from)
-            .map(|it| it.combinations())
-            .sum::<usize>()
-    );
-    println!(
-        "Part2: {}",
-        lines(read_input!())
-            .into_iter()
-            .map(Case::from)
+pub struct Day12;
+impl Solution for Day12 {
+    type Input = Vec<Case>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, |line| Case::try_from(line))
+    }
+    fn part1(cases: &Self::Input) -> Self::Part1 {
+        cases.iter().map(|it| it.combinations()).sum::<usize>()
+    }
+    fn part2(cases: &Self::Input) -> Self::Part2 {
+        cases
+            .iter()
             .map(|it| it.unfolded().combinations())
             .sum::<usize>()
-    );
+    }
+}
+
+utils::examples! {
+    Day12,
+    part1: ["example" => 21],
+    part2: ["example" => 525152],
 }
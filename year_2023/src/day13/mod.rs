@@ -1,6 +1,6 @@
 use std::{iter::Zip, vec::IntoIter};
 
-use utils::{lines, read_input};
+use utils::{parse::parse_paragraphs, solution::Solution, ParseError};
 
 fn transpose(strings: &[String]) -> Vec<String> {
     (0..strings[0].len())
@@ -49,7 +49,7 @@ impl Rule {
 }
 
 #[derive(Debug)]
-struct Mirrors {
+pub struct Mirrors {
     vertical: Vec<u32>,
     horizontal: Vec<u32>,
 }
@@ -64,7 +64,7 @@ impl Mirrors {
 
         MirrorPos::None
     }
-    fn find_mirror_pos(&self, lines: &Vec<u32>, rule: &Rule) -> Option<usize> {
+    fn find_mirror_pos(&self, lines: &[u32], rule: &Rule) -> Option<usize> {
         (0..(lines.len() - 1))
             .filter(|it| rule.compare(lines[*it], lines[it + 1]))
             .find(|pos| {
@@ -74,40 +74,64 @@ impl Mirrors {
             })
     }
 }
-impl From<Vec<String>> for Mirrors {
-    fn from(value: Vec<String>) -> Self {
-        Self {
-            vertical: transpose(&value)
-                .iter()
-                .map(|it| it.replace('#', "1").replace('.', "0"))
-                .map(|it| u32::from_str_radix(&it, 2).unwrap())
-                .collect(),
-            horizontal: value
-                .iter()
-                .map(|it| it.replace('#', "1").replace('.', "0"))
-                .map(|it| u32::from_str_radix(&it, 2).unwrap())
-                .collect(),
+impl TryFrom<&str> for Mirrors {
+    type Error = ParseError;
+
+    fn try_from(block: &str) -> Result<Self, Self::Error> {
+        let value = block.lines().map(str::to_string).collect::<Vec<_>>();
+        for (row, line) in value.iter().enumerate() {
+            let error = |column: usize, message: &str| {
+                Err(ParseError::new(column, line, message).offset_lines(row))
+            };
+            if let Some(column) = line.find(|it| it != '#' && it != '.') {
+                return error(column + 1, "expected '#' or '.'");
+            }
+            if line.len() != value[0].len() {
+                return error(1, "rows must have the same length");
+            }
+            if !(1..=32).contains(&line.len()) || value.len() > 32 {
+                return error(1, "patterns must have between 1 and 32 rows and columns");
+            }
         }
+
+        let to_bits = |it: &String| {
+            it.chars()
+                .fold(0, |bits, char| bits << 1 | u32::from(char == '#'))
+        };
+        Ok(Self {
+            vertical: transpose(&value).iter().map(to_bits).collect(),
+            horizontal: value.iter().map(to_bits).collect(),
+        })
     }
 }
 
-pub fn run() {
-    println!(
-        "Part1: {:?}",
-        read_input!()
-            .split("\n\n")
-            .map(|block| Mirrors::from(lines(block.to_string())))
+pub struct Day13;
+impl Solution for Day13 {
+    type Input = Vec<Mirrors>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_paragraphs(input, |block| Mirrors::try_from(block))
+    }
+    fn part1(mirrors: &Self::Input) -> Self::Part1 {
+        mirrors
+            .iter()
             .map(|mirrors| mirrors.find_mirror(Rule::NoSmudges))
             .map(MirrorPos::score)
             .sum::<usize>()
-    );
-    println!(
-        "Part2: {:?}",
-        read_input!()
-            .split("\n\n")
-            .map(|block| Mirrors::from(lines(block.to_string())))
+    }
+    fn part2(mirrors: &Self::Input) -> Self::Part2 {
+        mirrors
+            .iter()
             .map(|mirrors| mirrors.find_mirror(Rule::Smudges))
             .map(MirrorPos::score)
             .sum::<usize>()
-    );
+    }
+}
+
+utils::examples! {
+    Day13,
+    part1: ["example" => 405],
+    part2: ["example" => 400],
 }
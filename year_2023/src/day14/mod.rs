@@ -1,17 +1,6 @@
-use std::{collections::HashMap, fmt::Display, hash::Hash};
+use std::fmt::Display;
 
-use utils::{lines, read_input};
-
-#[derive(Clone, Debug, Hash, Eq, PartialEq)]
-struct Pos {
-    x: i32,
-    y: i32,
-}
-impl Pos {
-    fn new(x: i32, y: i32) -> Self {
-        Self { x, y }
-    }
-}
+use utils::{cycle, solution::Solution, Grid, ParseError};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 enum Element {
@@ -28,30 +17,22 @@ impl Display for Element {
         }
     }
 }
-impl From<char> for Element {
-    fn from(value: char) -> Self {
+impl TryFrom<char> for Element {
+    type Error = &'static str;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
-            '.' => Self::Space,
-            '#' => Self::CubeShapedRock,
-            'O' => Self::RoundedRock,
-            _ => panic!("Invalid input"),
+            '.' => Ok(Self::Space),
+            '#' => Ok(Self::CubeShapedRock),
+            'O' => Ok(Self::RoundedRock),
+            _ => Err("expected '.', '#' or 'O'"),
         }
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone)]
-struct Platform {
-    elements: HashMap<Pos, Element>,
-    width: i32,
-    height: i32,
-}
-impl Hash for Platform {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        (0..self.height).for_each(|y| {
-            (0..self.width)
-                .for_each(|x| (x, y, self.elements.get(&Pos::new(x, y)).unwrap()).hash(state))
-        });
-    }
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct Platform {
+    elements: Grid<Element>,
 }
 impl Platform {
     fn do_cycle(&mut self) {
@@ -61,103 +42,70 @@ impl Platform {
         });
     }
     fn tilt(&mut self) {
-        (0..self.width).for_each(|x| {
+        (0..self.elements.width()).for_each(|x| {
             let mut target_y = 0;
-            (0..self.height).for_each(|y| {
-                let element = self.elements.get(&Pos::new(x, y)).unwrap().clone();
-                match element {
-                    Element::Space => {}
-                    Element::CubeShapedRock => {
-                        target_y = y + 1;
-                    }
-                    Element::RoundedRock => {
-                        self.elements.insert(Pos::new(x, y), Element::Space);
-                        self.elements.insert(Pos::new(x, target_y), element);
-                        target_y += 1;
-                    }
+            (0..self.elements.height()).for_each(|y| match self.elements[(x, y)] {
+                Element::Space => {}
+                Element::CubeShapedRock => {
+                    target_y = y + 1;
+                }
+                Element::RoundedRock => {
+                    self.elements[(x, y)] = Element::Space;
+                    self.elements[(x, target_y)] = Element::RoundedRock;
+                    target_y += 1;
                 }
             });
         })
     }
     fn rotate(&mut self) {
-        let mut elements: HashMap<Pos, Element> = HashMap::new();
-        for (pos, element) in self.elements.iter() {
-            elements.insert(Pos::new(self.height - pos.y - 1, pos.x), element.clone());
-        }
-        let width = self.height;
-        let height = self.width;
-        self.width = width;
-        self.height = height;
-        self.elements = elements;
+        self.elements = self.elements.rotate_clockwise();
     }
     fn score(&self) -> i32 {
-        let mut count = 0;
-        for pos in self
-            .elements
+        self.elements
             .iter()
-            .filter_map(|(pos, el)| (el == &Element::RoundedRock).then_some(pos))
-        {
-            count += self
-                .elements
-                .iter()
-                .filter(|(other_pos, _)| other_pos.y >= pos.y && other_pos.x == pos.x)
-                .count() as i32;
-        }
-        count
-    }
-}
-impl From<Vec<String>> for Platform {
-    fn from(lines: Vec<String>) -> Self {
-        Self {
-            width: lines[0].len() as i32,
-            height: lines.len() as i32,
-            elements: lines
-                .into_iter()
-                .enumerate()
-                .flat_map(|(y, line)| {
-                    line.chars()
-                        .enumerate()
-                        .map(|(x, char)| (Pos::new(x as i32, y as i32), Element::from(char)))
-                        .collect::<Vec<_>>()
-                })
-                .collect(),
-        }
+            .filter(|(_, element)| **element == Element::RoundedRock)
+            .map(|((_, y), _)| (self.elements.height() - y) as i32)
+            .sum()
     }
 }
 
-pub fn run() {
-    println!("Part1: {}", part1());
-    println!("Part2: {}", part2());
+pub struct Day14;
+impl Solution for Day14 {
+    type Input = Platform;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Platform {
+            elements: input.parse()?,
+        })
+    }
+    fn part1(platform: &Self::Input) -> Self::Part1 {
+        load(platform)
+    }
+    fn part2(platform: &Self::Input) -> Self::Part2 {
+        load_after_cycles(platform)
+    }
 }
 
-fn part1() -> i32 {
-    let mut platform = Platform::from(lines(read_input!()));
+fn load(platform: &Platform) -> i32 {
+    let mut platform = platform.clone();
     platform.tilt();
     platform.score()
 }
 
-fn part2() -> i32 {
-    let mut hashes: HashMap<Platform, usize> = HashMap::new();
-    let mut platform = Platform::from(lines(read_input!()));
-
-    let mut cycles = 1;
-    let max_cycles = 1000000000;
-    loop {
+fn load_after_cycles(platform: &Platform) -> i32 {
+    let (cycle, platforms) = cycle::find(platform.clone(), |platform| {
+        let mut platform = platform.clone();
         platform.do_cycle();
-        if let Some(previous_count) = hashes.get(&platform) {
-            let remaining_cycles = (max_cycles - previous_count) % (cycles - previous_count);
-            (0..remaining_cycles).for_each(|_| platform.do_cycle());
-            break;
-        }
+        platform
+    });
 
-        hashes.insert(platform.clone(), cycles);
-
-        if cycles == max_cycles {
-            break;
-        }
-
-        cycles += 1;
-    }
+    platforms[cycle.extrapolate(1000000000)].score()
+}
 
-    platform.score()
+utils::examples! {
+    Day14,
+    part1: ["example" => 136],
+    part2: ["example" => 64],
 }
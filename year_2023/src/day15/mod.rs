@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use utils::{extract_one, read_input};
+use utils::{solution::Solution, ParseError};
 
 #[derive(Debug)]
 enum Op {
@@ -9,7 +9,8 @@ enum Op {
 }
 
 #[derive(Debug)]
-struct Sequence {
+pub struct Sequence {
+    step: String,
     label: String,
     op: Op,
 }
@@ -18,63 +19,87 @@ impl Sequence {
         ascii_hash(&self.label)
     }
 }
-impl From<String> for Sequence {
-    fn from(value: String) -> Self {
-        let label = extract_one(&value, "\\w+");
-        let op = if value.contains("=") {
-            Op::Add(extract_one(&value, "\\d+").parse().unwrap())
+impl TryFrom<&str> for Sequence {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (label, op) = if let Some(label) = value.strip_suffix('-') {
+            (label, Op::Remove)
+        } else if let Some((label, power)) = value.split_once('=') {
+            let power = power
+                .parse()
+                .map_err(|_| format!("{power:?} is not a focal length"))?;
+            (label, Op::Add(power))
         } else {
-            Op::Remove
+            return Err(format!(
+                "{value:?} must end with '-' or '=' and a focal length"
+            ));
         };
+        if label.is_empty() || !label.chars().all(|it| it.is_ascii_lowercase()) {
+            return Err(format!("{label:?} is not a label"));
+        }
 
-        Self { label, op }
+        Ok(Self {
+            step: value.to_string(),
+            label: label.to_string(),
+            op,
+        })
     }
 }
 
-pub fn run() {
-    println!("Part1: {}", part1());
-    println!("Part2: {}", part2());
+pub struct Day15;
+impl Solution for Day15 {
+    type Input = Vec<Sequence>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let line = input.replace('\n', "");
+        line.split(',')
+            .map(|step| {
+                let column = step.as_ptr() as usize - line.as_ptr() as usize + 1;
+                Sequence::try_from(step).map_err(|message| ParseError::new(column, &line, message))
+            })
+            .collect()
+    }
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        hash_sum(input)
+    }
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        focusing_power(input)
+    }
 }
 
-fn part1() -> u32 {
-    read_input!()
-        .replace('\n', "")
-        .split(',')
-        .map(ascii_hash)
-        .sum::<u32>()
+fn hash_sum(input: &[Sequence]) -> u32 {
+    input.iter().map(|seq| ascii_hash(&seq.step)).sum::<u32>()
 }
 
 type BoxNumber = u32;
 type LenseLabel = String;
 type LensePower = u32;
 
-fn part2() -> u32 {
+fn focusing_power(input: &[Sequence]) -> u32 {
     let mut lenses: HashMap<BoxNumber, Vec<(LenseLabel, LensePower)>> = HashMap::new();
 
-    read_input!()
-        .replace('\n', "")
-        .split(',')
-        .map(|it| it.to_string())
-        .map(Sequence::from)
-        .for_each(|seq| {
-            let lenses = lenses.entry(seq.find_box()).or_default();
-            let maybe_existing_pos = lenses.iter().position(|(label, _)| label == &seq.label);
+    input.iter().for_each(|seq| {
+        let lenses = lenses.entry(seq.find_box()).or_default();
+        let maybe_existing_pos = lenses.iter().position(|(label, _)| label == &seq.label);
 
-            match seq.op {
-                Op::Add(power) => {
-                    if let Some(existing_pos) = maybe_existing_pos {
-                        (*lenses)[existing_pos] = (seq.label.clone(), power);
-                    } else {
-                        (*lenses).push((seq.label.clone(), power));
-                    }
+        match &seq.op {
+            Op::Add(power) => {
+                if let Some(existing_pos) = maybe_existing_pos {
+                    (*lenses)[existing_pos] = (seq.label.clone(), *power);
+                } else {
+                    (*lenses).push((seq.label.clone(), *power));
                 }
-                Op::Remove => {
-                    if let Some(already_existing) = maybe_existing_pos {
-                        (*lenses).remove(already_existing);
-                    }
+            }
+            Op::Remove => {
+                if let Some(already_existing) = maybe_existing_pos {
+                    (*lenses).remove(already_existing);
                 }
-            };
-        });
+            }
+        };
+    });
 
     lenses
         .iter()
@@ -92,3 +117,9 @@ fn ascii_hash(word: &str) -> u32 {
     word.chars()
         .fold(0, |acc, act| ((acc + u32::from(act)) * 17) % 256)
 }
+
+utils::examples! {
+    Day15,
+    part1: ["example" => 1320],
+    part2: ["example" => 145],
+}
@@ -1,8 +1,8 @@
 use std::collections::{HashMap, HashSet};
 
-use utils::{lines, read_input};
+use utils::{solution::Solution, Grid, ParseError};
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 struct Beam {
     pos: Pos,
     dir: Dir,
@@ -46,7 +46,7 @@ enum Dir {
     Right,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 enum Point {
     Space,
     VerticalMirror,
@@ -75,26 +75,26 @@ impl Point {
         }
     }
 }
-impl From<char> for Point {
-    fn from(char: char) -> Self {
+impl TryFrom<char> for Point {
+    type Error = &'static str;
+
+    fn try_from(char: char) -> Result<Self, Self::Error> {
         match char {
-            '.' => Self::Space,
-            '|' => Self::VerticalMirror,
-            '-' => Self::HorizontalMirror,
-            '/' => Self::SlashMirror,
-            '\\' => Self::BackslashMirror,
-            _ => panic!("Invalid input"),
+            '.' => Ok(Self::Space),
+            '|' => Ok(Self::VerticalMirror),
+            '-' => Ok(Self::HorizontalMirror),
+            '/' => Ok(Self::SlashMirror),
+            '\\' => Ok(Self::BackslashMirror),
+            _ => Err("expected '.', '|', '-', '/' or '\\'"),
         }
     }
 }
 
-#[derive(Debug)]
-struct Map {
-    points: HashMap<Pos, Point>,
+#[derive(Clone, Debug)]
+pub struct Map {
+    points: Grid<Point>,
     point_beams: HashMap<Pos, HashSet<Dir>>,
     beams: Vec<Beam>,
-    width: i32,
-    height: i32,
 }
 impl Map {
     fn run(&mut self, beam: Beam) -> usize {
@@ -111,19 +111,23 @@ impl Map {
             .filter(|(_, x)| !x.is_empty())
             .count()
     }
+    fn width(&self) -> i32 {
+        self.points.width() as i32
+    }
+    fn height(&self) -> i32 {
+        self.points.height() as i32
+    }
     fn next(&mut self) -> Option<()> {
         let mut new_beams = vec![];
         for beam in self.beams.iter() {
             let generated_beams = self
                 .points
-                .get(&beam.pos)?
+                .get((beam.pos.x as usize, beam.pos.y as usize))?
                 .handle_beam(beam)
                 .into_iter()
                 .filter(|beam| {
-                    beam.pos.x >= 0
-                        && beam.pos.x < self.width
-                        && beam.pos.y >= 0
-                        && beam.pos.y < self.height
+                    self.points
+                        .contains(beam.pos.x as isize, beam.pos.y as isize)
                 })
                 .collect::<Vec<_>>();
 
@@ -142,46 +146,48 @@ impl Map {
         (!self.beams.is_empty()).then_some(())
     }
 }
-impl From<Vec<String>> for Map {
-    fn from(lines: Vec<String>) -> Self {
-        let width = lines[0].len() as i32;
-        let height = lines.len() as i32;
-
+impl From<Grid<Point>> for Map {
+    fn from(points: Grid<Point>) -> Self {
         Self {
-            points: lines
-                .into_iter()
-                .enumerate()
-                .flat_map(|(y, line)| {
-                    line.chars()
-                        .enumerate()
-                        .map(|(x, char)| (Pos::new(x as i32, y as i32), Point::from(char)))
-                        .collect::<Vec<_>>()
-                })
-                .collect(),
+            points,
             point_beams: Default::default(),
             beams: Default::default(),
-            width,
-            height,
         }
     }
 }
 
-pub fn run() {
-    let mut map = Map::from(lines(read_input!()));
+pub struct Day16;
+impl Solution for Day16 {
+    type Input = Map;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    println!(
-        "Part1: {:?}",
-        map.run(Beam::new(Pos::new(0, 0), Dir::Right))
-    );
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Map::from(input.parse::<Grid<Point>>()?))
+    }
+    fn part1(map: &Self::Input) -> Self::Part1 {
+        let mut map = map.clone();
 
-    let mut max = 0;
-    for x in 0..map.width {
-        max = max.max(map.run(Beam::new(Pos::new(x, 0), Dir::Down)));
-        max = max.max(map.run(Beam::new(Pos::new(x, map.height - 1), Dir::Up)));
+        map.run(Beam::new(Pos::new(0, 0), Dir::Right))
     }
-    for y in 0..map.width {
-        max = max.max(map.run(Beam::new(Pos::new(0, y), Dir::Right)));
-        max = max.max(map.run(Beam::new(Pos::new(map.width - 1, y), Dir::Left)));
+    fn part2(map: &Self::Input) -> Self::Part2 {
+        let mut map = map.clone();
+
+        let mut max = 0;
+        for x in 0..map.width() {
+            max = max.max(map.run(Beam::new(Pos::new(x, 0), Dir::Down)));
+            max = max.max(map.run(Beam::new(Pos::new(x, map.height() - 1), Dir::Up)));
+        }
+        for y in 0..map.width() {
+            max = max.max(map.run(Beam::new(Pos::new(0, y), Dir::Right)));
+            max = max.max(map.run(Beam::new(Pos::new(map.width() - 1, y), Dir::Left)));
+        }
+        max
     }
-    println!("Part2: {}", max);
+}
+
+utils::examples! {
+    Day16,
+    part1: ["example" => 46],
+    part2: ["example" => 51],
 }
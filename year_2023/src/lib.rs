@@ -0,0 +1,37 @@
+use utils::registry::Registry;
+
+mod day01;
+mod day02;
+mod day03;
+mod day04;
+mod day05;
+mod day06;
+mod day07;
+mod day08;
+mod day09;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day16;
+
+pub fn register(registry: &mut Registry) {
+    registry.add::<day01::Day01>(2023, 1);
+    registry.add::<day02::Day02>(2023, 2);
+    registry.add::<day03::Day03>(2023, 3);
+    registry.add::<day04::Day04>(2023, 4);
+    registry.add::<day05::Day05>(2023, 5);
+    registry.add::<day06::Day06>(2023, 6);
+    registry.add::<day07::Day07>(2023, 7);
+    registry.add::<day08::Day08>(2023, 8);
+    registry.add::<day09::Day09>(2023, 9);
+    registry.add::<day10::Day10>(2023, 10);
+    registry.add::<day11::Day11>(2023, 11);
+    registry.add::<day12::Day12>(2023, 12);
+    registry.add::<day13::Day13>(2023, 13);
+    registry.add::<day14::Day14>(2023, 14);
+    registry.add::<day15::Day15>(2023, 15);
+    registry.add::<day16::Day16>(2023, 16);
+}